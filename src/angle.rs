@@ -0,0 +1,294 @@
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+use num_traits::{Float, FloatConst};
+
+/// An angle in radians.
+///
+/// Functions taking angles accept `impl Into<Rad<T>>` so both [`Rad`] and [`Deg`] can be passed
+/// but a plain number can't, which makes the unit visible at every call site.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rad<T>(pub T);
+
+/// An angle in degrees, see [`Rad`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Deg<T>(pub T);
+
+macro_rules! impl_angle {
+    ($t: ident, $full_turn: expr) => {
+        impl<T: Float + FloatConst> $t<T> {
+            #[inline]
+            pub fn full_turn() -> Self {
+                $t($full_turn)
+            }
+            #[inline]
+            pub fn half_turn() -> Self {
+                Self::full_turn() / (T::one() + T::one())
+            }
+            /// Wraps the angle into the range [0, full turn).
+            #[inline]
+            pub fn normalized(self) -> Self {
+                let full = Self::full_turn().0;
+                let r = self.0 % full;
+                let r = if r < T::zero() { r + full } else { r };
+                // adding a full turn to tiny negative values can round up to a full turn
+                $t(if r < full { r } else { T::zero() })
+            }
+            /// Wraps the angle into the range (-half turn, half turn].
+            #[inline]
+            pub fn normalized_signed(self) -> Self {
+                let r = self.normalized().0;
+                $t(if r > Self::half_turn().0 {
+                    r - Self::full_turn().0
+                } else {
+                    r
+                })
+            }
+        }
+
+        impl<T: Float> $t<T> {
+            #[inline]
+            pub fn sin(self) -> T {
+                Rad::from(self).0.sin()
+            }
+            #[inline]
+            pub fn cos(self) -> T {
+                Rad::from(self).0.cos()
+            }
+            #[inline]
+            pub fn tan(self) -> T {
+                Rad::from(self).0.tan()
+            }
+            /// Returns `(sin, cos)` of the angle.
+            #[inline]
+            pub fn sin_cos(self) -> (T, T) {
+                Rad::from(self).0.sin_cos()
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $t<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                $t(self.0 + other.0)
+            }
+        }
+        impl<T: Sub<Output = T>> Sub for $t<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                $t(self.0 - other.0)
+            }
+        }
+        impl<T: Rem<Output = T>> Rem for $t<T> {
+            type Output = Self;
+
+            #[inline]
+            fn rem(self, other: Self) -> Self {
+                $t(self.0 % other.0)
+            }
+        }
+        /// The ratio between two angles.
+        impl<T: Div<Output = T>> Div for $t<T> {
+            type Output = T;
+
+            #[inline]
+            fn div(self, other: Self) -> T {
+                self.0 / other.0
+            }
+        }
+        impl<T: Mul<Output = T>> Mul<T> for $t<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, s: T) -> Self {
+                $t(self.0 * s)
+            }
+        }
+        impl<T: Div<Output = T>> Div<T> for $t<T> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, s: T) -> Self {
+                $t(self.0 / s)
+            }
+        }
+        impl<T: Neg<Output = T>> Neg for $t<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                $t(-self.0)
+            }
+        }
+        impl<T: AddAssign> AddAssign for $t<T> {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+        impl<T: SubAssign> SubAssign for $t<T> {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                self.0 -= other.0;
+            }
+        }
+        impl<T: MulAssign> MulAssign<T> for $t<T> {
+            #[inline]
+            fn mul_assign(&mut self, s: T) {
+                self.0 *= s;
+            }
+        }
+        impl<T: DivAssign> DivAssign<T> for $t<T> {
+            #[inline]
+            fn div_assign(&mut self, s: T) {
+                self.0 /= s;
+            }
+        }
+        impl Mul<$t<f32>> for f32 {
+            type Output = $t<f32>;
+
+            #[inline]
+            fn mul(self, angle: $t<f32>) -> $t<f32> {
+                $t(self * angle.0)
+            }
+        }
+        impl Mul<$t<f64>> for f64 {
+            type Output = $t<f64>;
+
+            #[inline]
+            fn mul(self, angle: $t<f64>) -> $t<f64> {
+                $t(self * angle.0)
+            }
+        }
+    };
+}
+
+impl_angle!(Rad, T::PI() + T::PI());
+impl_angle!(Deg, T::from(360.0).unwrap());
+
+impl<T: Float> Rad<T> {
+    #[inline]
+    pub fn asin(x: T) -> Self {
+        Self(x.asin())
+    }
+    #[inline]
+    pub fn acos(x: T) -> Self {
+        Self(x.acos())
+    }
+    #[inline]
+    pub fn atan(x: T) -> Self {
+        Self(x.atan())
+    }
+    /// The angle of the point `(x, y)` measured counter-clockwise from the x axis.
+    #[inline]
+    pub fn atan2(y: T, x: T) -> Self {
+        Self(y.atan2(x))
+    }
+}
+
+impl<T: Float> From<Deg<T>> for Rad<T> {
+    #[inline]
+    fn from(deg: Deg<T>) -> Self {
+        Self(deg.0.to_radians())
+    }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T> {
+    #[inline]
+    fn from(rad: Rad<T>) -> Self {
+        Self(rad.0.to_degrees())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Rad<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rad", self.0)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Deg<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}°", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::{FRAC_PI_2, PI, TAU};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Rad::from(Deg(180.0_f32)), Rad(PI));
+        assert_eq!(Deg::from(Rad(FRAC_PI_2)), Deg(90.0));
+        assert_eq!(Rad::<f64>::full_turn(), Rad(core::f64::consts::TAU));
+        assert_eq!(Deg::<f32>::half_turn(), Deg(180.0));
+        assert!((Deg(30.0_f32).sin() - 0.5).abs() < 1e-6);
+        assert_eq!(Rad(0.3_f32).sin_cos(), 0.3_f32.sin_cos());
+        assert_eq!(Rad::atan2(1.0_f32, 0.0), Rad(FRAC_PI_2));
+        assert_eq!(format!("{}", Deg(45.0)), "45°");
+        assert_eq!(format!("{}", Rad(1.5)), "1.5 rad");
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Deg(30.0) + Deg(60.0), Deg(90.0));
+        assert_eq!(Deg(30.0) - Deg(60.0), -Deg(30.0));
+        assert_eq!(Rad(1.0) * 2.0, 2.0 * Rad(1.0));
+        assert_eq!(Deg(90.0) / Deg(45.0), 2.0);
+        assert_eq!(Deg(90.0) / 3.0, Deg(30.0));
+        let mut a = Rad(1.0);
+        a += Rad(0.5);
+        a *= 2.0;
+        a -= Rad(1.0);
+        a /= 4.0;
+        assert_eq!(a, Rad(0.5));
+    }
+
+    #[test]
+    fn apis_accept_both_units() {
+        use crate::{Mat4x4, Quaternion, Vec2, Vec3};
+        let viewport = Vec2::new(16.0, 9.0);
+        assert_eq!(
+            Mat4x4::projection_matrix(viewport, 0.1, 100.0, Deg(90.0)),
+            Mat4x4::projection_matrix(viewport, 0.1, 100.0, Rad(FRAC_PI_2)),
+        );
+        assert_eq!(Mat4x4::rx(Deg(180.0)), Mat4x4::rx(Rad(PI)));
+        assert_eq!(
+            Quaternion::from_angle_around_axis(Vec3::Y, Deg(90.0)),
+            Quaternion::from_angle_around_axis(Vec3::Y, Rad(FRAC_PI_2)),
+        );
+        assert_eq!(
+            Vec2::from_angle(Deg(90.0)),
+            Vec2::from_angle(Rad(FRAC_PI_2))
+        );
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(Deg(370.0).normalized(), Deg(10.0));
+        assert_eq!(Deg(-90.0).normalized(), Deg(270.0));
+        assert_eq!(Deg(360.0).normalized(), Deg(0.0));
+        assert_eq!(Deg(270.0).normalized_signed(), Deg(-90.0));
+        assert_eq!(Deg(-180.0).normalized_signed(), Deg(180.0));
+        assert_eq!(Deg(180.0).normalized_signed(), Deg(180.0));
+        assert_eq!(Rad(-1e-9_f32).normalized(), Rad(0.0));
+        for i in -20..20 {
+            let a = Rad(i as f32 * 0.7);
+            let n = a.normalized();
+            let s = a.normalized_signed();
+            assert!((0.0..TAU).contains(&n.0));
+            assert!(s.0 > -PI && s.0 <= PI);
+            assert!((n.sin() - a.sin()).abs() < 1e-5 && (s.cos() - a.cos()).abs() < 1e-5);
+        }
+    }
+}
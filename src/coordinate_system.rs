@@ -0,0 +1,241 @@
+use core::ops::Neg;
+
+use crate::{Mat4x4, PolyVec3, Quaternion, Rad, Vec3};
+
+/// An axis convention used by a tool or file format. All of them use +X as the right direction.
+///
+/// - `ZUpRightHanded`: +Y forward and +Z up. Blender is also Z-up and right-handed, but models
+///   there face -Y (its front view looks along +Y), so they need a half turn around Z to face
+///   forward in this convention.
+/// - `YUpRightHanded`: +Y up and -Z forward, used by OpenGL and glTF.
+/// - `YUpLeftHanded`: +Y up and +Z forward, used by Direct3D and Unity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CoordinateSystem {
+    ZUpRightHanded,
+    YUpRightHanded,
+    YUpLeftHanded,
+}
+
+impl CoordinateSystem {
+    pub const ALL: [Self; 3] = [
+        Self::ZUpRightHanded,
+        Self::YUpRightHanded,
+        Self::YUpLeftHanded,
+    ];
+
+    pub const fn is_right_handed(self) -> bool {
+        !matches!(self, Self::YUpLeftHanded)
+    }
+
+    pub fn right(self) -> Vec3 {
+        Vec3::X
+    }
+
+    pub fn up(self) -> Vec3 {
+        match self {
+            Self::ZUpRightHanded => Vec3::Z,
+            Self::YUpRightHanded | Self::YUpLeftHanded => Vec3::Y,
+        }
+    }
+
+    pub fn forward(self) -> Vec3 {
+        match self {
+            Self::ZUpRightHanded => Vec3::Y,
+            Self::YUpRightHanded => -Vec3::Z,
+            Self::YUpLeftHanded => Vec3::Z,
+        }
+    }
+
+    /// Converts a position or direction from this coordinate system into `to`.
+    pub fn convert_vec3<T: Neg<Output = T> + Copy>(self, to: Self, v: PolyVec3<T>) -> PolyVec3<T> {
+        // every conversion swaps and negates axes, so the inputs are reordered into
+        // (right, forward, up) and then written into the axes of the target system
+        let [right, forward, up] = match self {
+            Self::ZUpRightHanded => [v.x, v.y, v.z],
+            Self::YUpRightHanded => [v.x, -v.z, v.y],
+            Self::YUpLeftHanded => [v.x, v.z, v.y],
+        };
+        match to {
+            Self::ZUpRightHanded => PolyVec3::new(right, forward, up),
+            Self::YUpRightHanded => PolyVec3::new(right, up, -forward),
+            Self::YUpLeftHanded => PolyVec3::new(right, up, forward),
+        }
+    }
+
+    /// The matrix converting vectors from this coordinate system into `to`. It only swaps and
+    /// negates axes so its inverse is its transpose.
+    pub fn conversion_matrix(self, to: Self) -> Mat4x4 {
+        let mut m = Mat4x4::identity();
+        for (i, axis) in [Vec3::X, Vec3::Y, Vec3::Z].into_iter().enumerate() {
+            let converted = self.convert_vec3(to, axis);
+            m[i][0] = converted.x;
+            m[i][1] = converted.y;
+            m[i][2] = converted.z;
+        }
+        m
+    }
+
+    /// Converts a rotation from this coordinate system into `to`. If the handedness changes, the
+    /// rotation direction is flipped so it still moves the converted vectors the same way.
+    pub fn convert_quaternion(self, to: Self, q: Quaternion) -> Quaternion {
+        let v = self.convert_vec3(to, q.v());
+        let v = if self.is_right_handed() == to.is_right_handed() {
+            v
+        } else {
+            -v
+        };
+        Quaternion::new(q.w, v.x, v.y, v.z)
+    }
+
+    /// Converts a transformation matrix from this coordinate system into `to` so that it
+    /// transforms converted vectors the same way.
+    pub fn convert_mat4(self, to: Self, m: Mat4x4) -> Mat4x4 {
+        let c = self.conversion_matrix(to);
+        c * m * c.transpose()
+    }
+}
+
+impl<T: num_traits::Float> PolyVec3<T> {
+    /// Creates a vector from spherical coordinates in the ISO convention: `theta` is the polar
+    /// angle from the +Z axis and `phi` is the azimuth in the XY plane measured from +X.
+    #[inline]
+    pub fn from_spherical(radius: T, theta: impl Into<Rad<T>>, phi: impl Into<Rad<T>>) -> Self {
+        let (sin_theta, cos_theta) = theta.into().sin_cos();
+        let (sin_phi, cos_phi) = phi.into().sin_cos();
+        Self::new(
+            radius * sin_theta * cos_phi,
+            radius * sin_theta * sin_phi,
+            radius * cos_theta,
+        )
+    }
+
+    /// Returns the spherical coordinates `(radius, theta, phi)` as described in
+    /// [`from_spherical`](Self::from_spherical). `theta` is in the range [0, pi] and `phi` in the
+    /// range [-pi, pi].
+    #[inline]
+    pub fn to_spherical(&self) -> (T, Rad<T>, Rad<T>) {
+        (
+            self.magnitude(),
+            Rad::atan2(self.x.hypot(self.y), self.z),
+            Rad::atan2(self.y, self.x),
+        )
+    }
+
+    /// Creates a vector from cylindrical coordinates around the Z axis, with the azimuth `phi`
+    /// measured from +X.
+    #[inline]
+    pub fn from_cylindrical(radius: T, phi: impl Into<Rad<T>>, z: T) -> Self {
+        let (sin, cos) = phi.into().sin_cos();
+        Self::new(radius * cos, radius * sin, z)
+    }
+
+    /// Returns the cylindrical coordinates `(radius, phi, z)` as described in
+    /// [`from_cylindrical`](Self::from_cylindrical).
+    #[inline]
+    pub fn to_cylindrical(&self) -> (T, Rad<T>, T) {
+        (self.x.hypot(self.y), Rad::atan2(self.y, self.x), self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_vec_eq, random_vec3};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn axes() {
+        use CoordinateSystem::*;
+        assert_eq!(
+            ZUpRightHanded.convert_vec3(YUpRightHanded, Vec3::new(1.0, 2.0, 3.0)),
+            Vec3::new(1.0, 3.0, -2.0)
+        );
+        assert_eq!(
+            ZUpRightHanded.convert_vec3(YUpLeftHanded, Vec3::new(1.0, 2.0, 3.0)),
+            Vec3::new(1.0, 3.0, 2.0)
+        );
+        for from in CoordinateSystem::ALL {
+            for to in CoordinateSystem::ALL {
+                assert_eq!(from.convert_vec3(to, from.up()), to.up());
+                assert_eq!(from.convert_vec3(to, from.forward()), to.forward());
+                assert_eq!(from.convert_vec3(to, from.right()), to.right());
+                assert_eq!(
+                    from.right().cross(from.forward()) == from.up(),
+                    from.is_right_handed()
+                );
+                let v = PolyVec3::new(1, -2, 3);
+                assert_eq!(to.convert_vec3(from, from.convert_vec3(to, v)), v);
+            }
+        }
+    }
+
+    #[test]
+    fn transformations() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for from in CoordinateSystem::ALL {
+            for to in CoordinateSystem::ALL {
+                let q = Quaternion::from_angle_around_axis(
+                    random_vec3(&mut rng),
+                    Rad(rng.gen_range(-3.0..3.0)),
+                );
+                let mut m = q.matrix();
+                m.translate(random_vec3(&mut rng));
+                let v = random_vec3(&mut rng);
+
+                let converted_q = from.convert_quaternion(to, q);
+                let converted_m = from.convert_mat4(to, m);
+                let transformed = from.convert_vec3(to, q * v);
+                assert_vec_eq(converted_q * from.convert_vec3(to, v), transformed, 1e-4);
+                assert_vec_eq(
+                    converted_m.to_quaternion() * from.convert_vec3(to, v),
+                    transformed,
+                    1e-4,
+                );
+                let [tx, ty, tz, _] = converted_m[3];
+                assert_vec_eq(
+                    Vec3::new(tx, ty, tz),
+                    from.convert_vec3(to, Vec3::new(m[3][0], m[3][1], m[3][2])),
+                    1e-4,
+                );
+                let c = from.conversion_matrix(to);
+                for (i, axis) in [Vec3::X, Vec3::Y, Vec3::Z].into_iter().enumerate() {
+                    let [x, y, z, _] = c[i];
+                    assert_eq!(Vec3::new(x, y, z), from.convert_vec3(to, axis));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn spherical_and_cylindrical() {
+        use core::f32::consts::{FRAC_PI_2, PI};
+        assert_vec_eq(
+            Vec3::from_spherical(2.0, Rad(0.0), Rad(1.0)),
+            Vec3::Z * 2.0,
+            1e-4,
+        );
+        assert_vec_eq(
+            Vec3::from_spherical(1.0, Rad(FRAC_PI_2), Rad(FRAC_PI_2)),
+            Vec3::Y,
+            1e-4,
+        );
+        assert_eq!(Vec3::ZERO.to_spherical(), (0.0, Rad(0.0), Rad(0.0)));
+        assert_eq!((-Vec3::Z).to_spherical().1, Rad(PI));
+        assert_vec_eq(
+            Vec3::from_cylindrical(2.0, Rad(PI), 5.0),
+            Vec3::new(-2.0, 0.0, 5.0),
+            1e-4,
+        );
+
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..100 {
+            let v = random_vec3(&mut rng);
+            let (r, theta, phi) = v.to_spherical();
+            assert!((0.0..=PI).contains(&theta.0));
+            assert_vec_eq(Vec3::from_spherical(r, theta, phi), v, 1e-4);
+            let (r, phi, z) = v.to_cylindrical();
+            assert_vec_eq(Vec3::from_cylindrical(r, phi, z), v, 1e-4);
+        }
+    }
+}
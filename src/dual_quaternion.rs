@@ -0,0 +1,424 @@
+use crate::{Mat4x4, Quaternion, Vec3};
+
+/// A rigid transformation (rotation followed by a translation) represented as a dual quaternion.
+///
+/// Unlike matrices, dual quaternions can be blended without introducing scale or shear, which
+/// avoids the volume loss of linear blend skinning. All operations assume unit dual quaternions
+/// unless stated otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DualQuaternion {
+    pub real: Quaternion,
+    pub dual: Quaternion,
+}
+impl Default for DualQuaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+const ZERO: Quaternion = Quaternion {
+    w: 0.0,
+    x: 0.0,
+    y: 0.0,
+    z: 0.0,
+};
+
+/// the pure quaternion (0, v)
+fn pure(v: Vec3) -> Quaternion {
+    Quaternion {
+        w: 0.0,
+        x: v.x,
+        y: v.y,
+        z: v.z,
+    }
+}
+
+impl DualQuaternion {
+    /// The identity transformation.
+    pub const IDENTITY: Self = Self {
+        real: Quaternion::IDENTITY,
+        dual: ZERO,
+    };
+
+    /// Creates a transformation that first rotates and then translates.
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vec3) -> Self {
+        Self {
+            real: rotation,
+            dual: pure(translation) * rotation * 0.5,
+        }
+    }
+
+    pub fn from_rotation(rotation: Quaternion) -> Self {
+        Self {
+            real: rotation,
+            dual: ZERO,
+        }
+    }
+
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self::from_rotation_translation(Quaternion::IDENTITY, translation)
+    }
+
+    /// Extracts the rotation and translation from a transformation matrix. Scaling is removed.
+    pub fn from_mat4(m: &Mat4x4) -> Self {
+        Self::from_rotation_translation(m.to_quaternion(), Vec3::new(m[3][0], m[3][1], m[3][2]))
+    }
+
+    #[must_use = "only retrieves the rotation"]
+    pub fn rotation(self) -> Quaternion {
+        self.real
+    }
+
+    #[must_use = "only calculates the translation"]
+    pub fn translation(self) -> Vec3 {
+        (self.dual * self.real.conjugate() * 2.0).v()
+    }
+
+    /// Retrieves the equivalent transformation matrix.
+    #[must_use = "only calculates the transformation matrix"]
+    pub fn matrix(self) -> Mat4x4 {
+        let mut m = self.real.matrix();
+        m.translate(self.translation());
+        m
+    }
+
+    /// Conjugates both parts of the dual quaternion.
+    #[must_use = "returns the new, conjugated dual quaternion"]
+    pub fn conjugate(self) -> Self {
+        Self {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Returns the inverse transformation. This also works for non-unit dual quaternions.
+    #[must_use = "returns the new, inverted dual quaternion"]
+    pub fn inverse(self) -> Self {
+        let real = self.real.inverse();
+        Self {
+            real,
+            dual: -(real * self.dual * real),
+        }
+    }
+
+    /// Normalizes the real part to unit length and makes the dual part orthogonal to it.
+    #[must_use = "returns the new, normalized dual quaternion"]
+    pub fn normalized(self) -> Self {
+        let l = self.real.length();
+        let real = self.real / l;
+        let dual = self.dual / l;
+        Self {
+            real,
+            dual: dual - real * real.dot(dual),
+        }
+    }
+
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Transforms a point by rotating and then translating it.
+    #[must_use = "only calculates the transformed point"]
+    pub fn transform_point(self, p: Vec3) -> Vec3 {
+        self.real.rotate_vec3(p) + self.translation()
+    }
+
+    /// Transforms a direction. Only the rotation is applied.
+    #[must_use = "only calculates the transformed vector"]
+    pub fn transform_vector(self, v: Vec3) -> Vec3 {
+        self.real.rotate_vec3(v)
+    }
+
+    /// Raises the transformation to a real power by scaling the screw motion it describes.
+    #[must_use = "only calculates the power"]
+    pub fn powf(self, t: f32) -> Self {
+        // Source: Kavan, L. et al. (2008). Geometric Skinning with Approximate Dual Quaternion
+        // Blending.
+        let l = self.real.v().magnitude();
+        if l < 1e-6 {
+            // pure translation
+            return Self {
+                real: self.real,
+                dual: self.dual * t,
+            };
+        }
+        // screw parameters: rotation angle, translation along the axis, axis and moment
+        let angle = 2.0 * l.atan2(self.real.w);
+        let pitch = -2.0 * self.dual.w / l;
+        let axis = self.real.v() / l;
+        let moment = (self.dual.v() - axis * (pitch * 0.5 * self.real.w)) / l;
+
+        let (sin, cos) = (angle * t * 0.5).sin_cos();
+        let pitch = pitch * t;
+        let dual = moment * sin + axis * (pitch * 0.5 * cos);
+        Self {
+            real: Quaternion {
+                w: cos,
+                x: axis.x * sin,
+                y: axis.y * sin,
+                z: axis.z * sin,
+            },
+            dual: Quaternion {
+                w: -pitch * 0.5 * sin,
+                x: dual.x,
+                y: dual.y,
+                z: dual.z,
+            },
+        }
+    }
+
+    /// Screw linear interpolation. Interpolates along the screw motion between the
+    /// transformations with constant rotational and translational velocity, taking the shortest
+    /// path.
+    #[must_use = "returns the interpolated dual quaternion"]
+    pub fn sclerp(self, other: Self, t: f32) -> Self {
+        let other = if self.real.dot(other.real) < 0.0 {
+            -other
+        } else {
+            other
+        };
+        self * (self.conjugate() * other).powf(t)
+    }
+
+    /// Dual quaternion linear blending (DLB) of weighted transformations, as used for skinning.
+    /// All transformations are flipped into the hemisphere of the first one before blending.
+    /// Returns the identity if there are no transformations.
+    #[must_use = "returns the blended dual quaternion"]
+    pub fn dlb(transforms: impl IntoIterator<Item = (Self, f32)>) -> Self {
+        let mut transforms = transforms.into_iter();
+        let Some((first, weight)) = transforms.next() else {
+            return Self::IDENTITY;
+        };
+        let mut sum = first * weight;
+        for (dq, weight) in transforms {
+            let weight = if first.real.dot(dq.real) < 0.0 {
+                -weight
+            } else {
+                weight
+            };
+            sum = sum + dq * weight;
+        }
+        sum.normalized()
+    }
+}
+
+impl From<Mat4x4> for DualQuaternion {
+    fn from(m: Mat4x4) -> Self {
+        Self::from_mat4(&m)
+    }
+}
+impl From<DualQuaternion> for Mat4x4 {
+    fn from(dq: DualQuaternion) -> Self {
+        dq.matrix()
+    }
+}
+
+impl core::ops::Mul<Self> for DualQuaternion {
+    type Output = Self;
+
+    /// Composes the transformations, `rhs` is applied first.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            real: self.real * rhs.real,
+            dual: self.real * rhs.dual + self.dual * rhs.real,
+        }
+    }
+}
+impl core::ops::MulAssign<Self> for DualQuaternion {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl core::ops::Add<Self> for DualQuaternion {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            real: self.real + rhs.real,
+            dual: self.dual + rhs.dual,
+        }
+    }
+}
+impl core::ops::Sub<Self> for DualQuaternion {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            real: self.real - rhs.real,
+            dual: self.dual - rhs.dual,
+        }
+    }
+}
+impl core::ops::Mul<f32> for DualQuaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            real: self.real * rhs,
+            dual: self.dual * rhs,
+        }
+    }
+}
+impl core::ops::Neg for DualQuaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            real: -self.real,
+            dual: -self.dual,
+        }
+    }
+}
+
+#[cfg(feature = "binverse")]
+impl<W: std::io::Write> binverse::serialize::Serialize<W> for DualQuaternion {
+    #[inline]
+    fn serialize(
+        &self,
+        s: &mut binverse::streams::Serializer<W>,
+    ) -> binverse::error::BinverseResult<()> {
+        self.real.serialize(s)?;
+        self.dual.serialize(s)
+    }
+}
+#[cfg(feature = "binverse")]
+impl<R: std::io::Read> binverse::serialize::Deserialize<R> for DualQuaternion {
+    #[inline]
+    fn deserialize(
+        d: &mut binverse::streams::Deserializer<R>,
+    ) -> binverse::error::BinverseResult<Self> {
+        Ok(Self {
+            real: d.deserialize()?,
+            dual: d.deserialize()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{assert_vec_eq, random_vec3},
+        Rad,
+    };
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn random_transform(rng: &mut impl Rng) -> DualQuaternion {
+        let rotation =
+            Quaternion::from_angle_around_axis(random_vec3(rng), Rad(rng.gen_range(-3.0..3.0)));
+        DualQuaternion::from_rotation_translation(rotation, random_vec3(rng))
+    }
+
+    fn mat_transform_point(m: &Mat4x4, p: Vec3) -> Vec3 {
+        let row = |r: usize| m[0][r] * p.x + m[1][r] * p.y + m[2][r] * p.z + m[3][r];
+        Vec3::new(row(0), row(1), row(2))
+    }
+
+    #[test]
+    fn transform_points() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..500 {
+            let a = random_transform(&mut rng);
+            let b = random_transform(&mut rng);
+            let p = random_vec3(&mut rng);
+
+            assert_vec_eq(
+                a.transform_point(p),
+                a.rotation() * p + a.translation(),
+                1e-3,
+            );
+            assert_vec_eq(
+                a.transform_point(p),
+                mat_transform_point(&a.matrix(), p),
+                1e-3,
+            );
+            assert_vec_eq(
+                (a * b).transform_point(p),
+                a.transform_point(b.transform_point(p)),
+                1e-3,
+            );
+            assert_vec_eq(a.inverse().transform_point(a.transform_point(p)), p, 1e-3);
+            assert_vec_eq(a.conjugate().transform_point(a.transform_point(p)), p, 1e-3);
+
+            let m = DualQuaternion::from_mat4(&a.matrix());
+            assert_vec_eq(m.transform_point(p), a.transform_point(p), 1e-3);
+            assert_vec_eq(
+                DualQuaternion::from(Mat4x4::from(a)).transform_point(p),
+                a.transform_point(p),
+                1e-3,
+            );
+        }
+    }
+
+    #[test]
+    fn sclerp() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..500 {
+            let a = random_transform(&mut rng);
+            let b = random_transform(&mut rng);
+            let p = random_vec3(&mut rng);
+            assert_vec_eq(
+                a.sclerp(b, 0.0).transform_point(p),
+                a.transform_point(p),
+                1e-3,
+            );
+            assert_vec_eq(
+                a.sclerp(b, 1.0).transform_point(p),
+                b.transform_point(p),
+                1e-3,
+            );
+            assert_vec_eq(
+                a.sclerp(-b, 1.0).transform_point(p),
+                b.transform_point(p),
+                1e-3,
+            );
+
+            let mid = a.sclerp(b, 0.5);
+            assert!((mid.real.length() - 1.0).abs() < 1e-4);
+            assert!(mid.real.dot(mid.dual).abs() < 1e-3);
+            // half of the motion twice is the whole motion
+            let half = a.conjugate() * mid;
+            assert_vec_eq(
+                (a * half * half).transform_point(p),
+                b.transform_point(p),
+                1e-3,
+            );
+        }
+
+        let a = DualQuaternion::from_translation(Vec3::new(1.0, 0.0, 0.0));
+        let b = DualQuaternion::from_translation(Vec3::new(3.0, 2.0, 0.0));
+        assert_vec_eq(
+            a.sclerp(b, 0.25).translation(),
+            Vec3::new(1.5, 0.5, 0.0),
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn dlb() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..500 {
+            let a = random_transform(&mut rng);
+            let b = random_transform(&mut rng);
+            let p = random_vec3(&mut rng);
+            let blended = DualQuaternion::dlb([(a, 1.0), (b, 0.0)]);
+            assert_vec_eq(blended.transform_point(p), a.transform_point(p), 1e-3);
+            let blended = DualQuaternion::dlb([(a, 0.0), (-b, 1.0)]);
+            assert_vec_eq(blended.transform_point(p), b.transform_point(p), 1e-3);
+
+            let blended = DualQuaternion::dlb([(a, 0.5), (-a, 0.5)]);
+            assert_vec_eq(blended.transform_point(p), a.transform_point(p), 1e-3);
+        }
+        assert_eq!(DualQuaternion::dlb([]), DualQuaternion::IDENTITY);
+
+        // blending two rotations around the same axis keeps the distance from the axis, which is
+        // where linear blend skinning collapses
+        let a =
+            DualQuaternion::from_rotation(Quaternion::from_angle_around_axis(Vec3::X, Rad(0.0)));
+        let b =
+            DualQuaternion::from_rotation(Quaternion::from_angle_around_axis(Vec3::X, Rad(3.0)));
+        let p = DualQuaternion::dlb([(a, 0.5), (b, 0.5)]).transform_point(Vec3::Y);
+        assert!((p.magnitude() - 1.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,80 @@
+/// The axis sequence of a set of euler angles.
+///
+/// The first six variants are the Tait–Bryan sequences which rotate around three different axes,
+/// the last six are the proper euler sequences which rotate around the first axis again at the end.
+///
+/// Whether the rotations are applied around the fixed world axes (extrinsic) or around the axes
+/// rotated by the previous rotations (intrinsic) is selected by the function taking the order,
+/// for example [`Quaternion::from_euler`](crate::Quaternion::from_euler) and
+/// [`Quaternion::from_euler_intrinsic`](crate::Quaternion::from_euler_intrinsic).
+/// Extrinsic XYZ rotations by (a, b, c) are the same as intrinsic ZYX rotations by (c, b, a).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    pub const ALL: [Self; 12] = [
+        Self::XYZ,
+        Self::XZY,
+        Self::YXZ,
+        Self::YZX,
+        Self::ZXY,
+        Self::ZYX,
+        Self::XYX,
+        Self::XZX,
+        Self::YXY,
+        Self::YZY,
+        Self::ZXZ,
+        Self::ZYZ,
+    ];
+
+    /// Returns the axis indices (x = 0, y = 1, z = 2) in the order they are rotated around.
+    pub const fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+            Self::XYX => [0, 1, 0],
+            Self::XZX => [0, 2, 0],
+            Self::YXY => [1, 0, 1],
+            Self::YZY => [1, 2, 1],
+            Self::ZXZ => [2, 0, 2],
+            Self::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Returns the order with the axis sequence reversed.
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::XYZ => Self::ZYX,
+            Self::XZY => Self::YZX,
+            Self::YXZ => Self::ZXY,
+            Self::YZX => Self::XZY,
+            Self::ZXY => Self::YXZ,
+            Self::ZYX => Self::XYZ,
+            proper => proper,
+        }
+    }
+
+    /// Returns true for the proper euler sequences where the first and last axis are the same.
+    pub const fn is_proper(self) -> bool {
+        let [a, _, c] = self.axes();
+        a == c
+    }
+}
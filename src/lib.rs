@@ -1,16 +1,34 @@
 #![cfg_attr(not(feature = "binverse"), no_std)]
 
+#[cfg(all(test, not(feature = "binverse")))]
+#[macro_use]
+extern crate std;
+
+pub mod shader;
 pub mod swizzle;
 
+mod angle;
+mod coordinate_system;
+mod dual_quaternion;
+mod euler;
 mod mat;
 mod quaternion;
+#[cfg(test)]
+mod test_util;
 mod vec;
 
 use core::ops;
 
+pub use angle::{Deg, Rad};
+pub use coordinate_system::CoordinateSystem;
+pub use dual_quaternion::DualQuaternion;
+pub use euler::EulerOrder;
 pub use mat::Mat;
-pub use quaternion::Quaternion;
-pub use vec::{Convert, PolyVec2, PolyVec3, PolyVec4, VecFrom, VecInto, W, X, Y, Z};
+pub use quaternion::{Quaternion, QuaternionSpline};
+pub use vec::{
+    Convert, PolyVec2, PolyVec3, PolyVec4, RoundToInt, SVec, SaturatingCast, TryConvert,
+    TryConvertError, VecFrom, VecInto, Vector, W, X, Y, Z,
+};
 
 pub type Vec2 = PolyVec2<f32>;
 pub type Vec2i = PolyVec2<i32>;
@@ -86,6 +104,331 @@ mod test {
         );
     }
 
+    #[test]
+    fn vector_algebra() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(4.0, -5.0, 6.0);
+        assert_eq!(a.dot(b), 12.0);
+        assert_eq!(Vec2i::new(2, 3).dot(Vec2i::new(4, 5)), 23);
+        assert_eq!(a.distance_squared(b), 9.0 + 49.0 + 9.0);
+        assert_eq!(Vec2::new(1.0, 1.0).distance(Vec2::new(4.0, 5.0)), 5.0);
+
+        assert_eq!(b.project_onto(Vec3::X * 2.0), Vec3::X * 4.0);
+        assert_eq!(b.reject_from(Vec3::X * 2.0), Vec3::new(0.0, -5.0, 6.0));
+        assert_eq!(b.project_onto(Vec3::ZERO), Vec3::ZERO);
+        assert_eq!(Vec2::new(1.0, -1.0).reflect(Vec2::Y), Vec2::new(1.0, 1.0));
+
+        let incident = Vec2::new(1.0, -1.0).normalized();
+        assert_eq!(incident.refract(Vec2::Y, 1.0), Some(incident));
+        let refracted = incident.refract(Vec2::Y, 0.5).unwrap();
+        assert!((refracted.magnitude() - 1.0).abs() < 1e-6);
+        assert!((refracted.x - 0.5 * incident.x).abs() < 1e-6);
+        assert_eq!(incident.refract(Vec2::Y, 2.0), None);
+
+        assert_eq!(a.lerp(b, 0.5), Vec3::new(2.5, -1.5, 4.5));
+        assert_eq!(Vec4::ZERO.move_towards(Vec4::X * 3.0, 1.0), Vec4::X);
+        assert_eq!(Vec4::ZERO.move_towards(Vec4::X * 3.0, 5.0), Vec4::X * 3.0);
+        assert_eq!((Vec3::X * 5.0).clamp_length(1.0, 2.0), Vec3::X * 2.0);
+        assert_eq!((Vec3::X * 0.5).clamp_length(1.0, 2.0), Vec3::X);
+        assert_eq!(Vec3::ZERO.clamp_length(1.0, 2.0), Vec3::ZERO);
+
+        assert_eq!(Vec3::ZERO.try_normalized(), None);
+        assert_eq!(Vec3::ZERO.normalized(), Vec3::ZERO);
+        assert_eq!((Vec3::Z * 3.0).try_normalized(), Some(Vec3::Z));
+    }
+
+    #[test]
+    fn elementwise_functions() {
+        let v = Vec3::new(-1.5, 0.25, 4.0);
+        assert_eq!(v.abs(), Vec3::new(1.5, 0.25, 4.0));
+        assert_eq!(v.signum(), Vec3::new(-1.0, 1.0, 1.0));
+        assert_eq!(v.fract(), Vec3::new(-0.5, 0.25, 0.0));
+        assert_eq!(v.trunc(), Vec3::new(-1.0, 0.0, 4.0));
+        assert_eq!(v.abs().sqrt(), Vec3::new(1.5_f32.sqrt(), 0.5, 2.0));
+        assert_eq!(v.recip(), Vec3::new(-1.0 / 1.5, 4.0, 0.25));
+        assert_eq!(Vec2::ZERO.exp(), Vec2::ONE);
+        assert_eq!(Vec2::ONE.ln(), Vec2::ZERO);
+        assert_eq!(v.powi(2), Vec3::new(2.25, 0.0625, 16.0));
+        assert_eq!(v.abs().powf(0.5), v.abs().sqrt());
+        assert_eq!(
+            v.mul_add(Vec3::fill(2.0), Vec3::ONE),
+            Vec3::new(-2.0, 1.5, 9.0)
+        );
+
+        let other = Vec3::new(1.0, f32::NAN, -4.0);
+        assert_eq!(v.min(other), Vec3::new(-1.5, 0.25, -4.0));
+        assert_eq!(v.max(other), Vec3::new(1.0, 0.25, 4.0));
+        assert_eq!(other.min(v), Vec3::new(-1.5, 0.25, -4.0));
+        assert_eq!(v.clamp(Vec3::ZERO, Vec3::ONE), Vec3::new(0.0, 0.25, 1.0));
+        assert_eq!(v.copysign(Vec3::fill(-1.0)), Vec3::new(-1.5, -0.25, -4.0));
+        assert_eq!(other.is_nan(), PolyVec3::new(false, true, false));
+        assert_eq!(other.is_finite(), PolyVec3::new(true, false, true));
+        assert_eq!(
+            Vec2::new(1.0, -1.0).atan2(Vec2::new(0.0, -1.0)),
+            Vec2::new(
+                core::f32::consts::FRAC_PI_2,
+                -3.0 * core::f32::consts::FRAC_PI_4
+            )
+        );
+
+        let i = Vec3i::new(-3, 0, 7);
+        assert_eq!(i.abs(), Vec3i::new(3, 0, 7));
+        assert_eq!(i.signum(), Vec3i::new(-1, 0, 1));
+        assert_eq!(i.min(Vec3i::fill(1)), Vec3i::new(-3, 0, 1));
+        assert_eq!(i.max(Vec3i::fill(1)), Vec3i::new(1, 1, 7));
+        assert_eq!(
+            i.clamp(Vec3i::fill(-1), Vec3i::fill(5)),
+            Vec3i::new(-1, 0, 5)
+        );
+    }
+
+    #[test]
+    fn vec2_operations() {
+        use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        let close = |a: Vec2, b: Vec2| (a - b).magnitude() < 1e-6;
+
+        let v = Vec2::new(2.0, 1.0);
+        assert_eq!(v.perp(), Vec2::new(-1.0, 2.0));
+        assert_eq!(v.perp().dot(v), 0.0);
+        assert_eq!(Vec2::X.perp_dot(Vec2::Y), 1.0);
+        assert_eq!(Vec2::Y.perp_dot(Vec2::X), -1.0);
+        assert_eq!(Vec2i::new(3, 4).perp(), Vec2i::new(-4, 3));
+
+        assert!(close(Vec2::from_angle(Rad(FRAC_PI_2)), Vec2::Y));
+        assert_eq!(Vec2::new(-1.0, -1.0).to_angle(), Rad(-3.0 * FRAC_PI_4));
+        assert!(close(v.rotate(Rad(FRAC_PI_2)), v.perp()));
+        assert!(close(v.rotate(Rad(PI)), -v));
+        assert!(close(
+            v.rotate_by(Vec2::from_angle(Rad(0.3))),
+            v.rotate(Rad(0.3))
+        ));
+
+        assert!((Vec2::X.signed_angle_to(Vec2::Y).0 - FRAC_PI_2).abs() < 1e-6);
+        assert!((Vec2::Y.signed_angle_to(Vec2::X).0 + FRAC_PI_2).abs() < 1e-6);
+        let a = Vec2::from_angle(Rad(2.5));
+        let b = Vec2::from_angle(Rad(-2.5));
+        assert!((a.signed_angle_to(b).0 - (2.0 * PI - 5.0)).abs() < 1e-5);
+
+        let (r, angle) = Vec2::new(0.0, -3.0).to_polar();
+        assert_eq!((r, angle), (3.0, Rad(-FRAC_PI_2)));
+        assert!(close(Vec2::from_polar(r, angle), Vec2::new(0.0, -3.0)));
+    }
+
+    #[test]
+    fn vec3_operations() {
+        use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+        let close = |a: Vec3, b: Vec3| (a - b).magnitude() < 1e-5;
+
+        let (a, b, c) = (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.5, 1.0), Vec3::Z);
+        assert_eq!(Vec3::X.scalar_triple(Vec3::Y, Vec3::Z), 1.0);
+        assert_eq!(a.scalar_triple(b, c), c.scalar_triple(a, b));
+        assert_eq!(a.vector_triple(b, c), b * a.dot(c) - c * a.dot(b));
+
+        for v in [
+            Vec3::X,
+            -Vec3::Z,
+            Vec3::Z,
+            a,
+            b,
+            -a,
+            Vec3::new(0.0, -0.0, -1.0),
+        ] {
+            let o = v.any_orthogonal();
+            assert!(o.magnitude() > 0.1 && o.dot(v).abs() < 1e-6, "{v} -> {o}");
+
+            let n = v.normalized();
+            let (b1, b2) = n.orthonormal_basis();
+            for (x, y) in [(b1, b2), (b1, n), (b2, n)] {
+                assert!(x.dot(y).abs() < 1e-6);
+            }
+            assert!((b1.magnitude() - 1.0).abs() < 1e-6);
+            assert!((b2.magnitude() - 1.0).abs() < 1e-6);
+            assert!(close(b1.cross(b2), n));
+        }
+
+        assert!(close(
+            Vec3::X.rotate_around(Vec3::Z * 2.0, Rad(FRAC_PI_2)),
+            Vec3::Y
+        ));
+        let q = Quaternion::from_angle_around_axis(b, Rad(0.7));
+        assert!(close(a.rotate_around(b, Rad(0.7)), q.rotate_vec3(a)));
+
+        assert!((Vec3::X.signed_angle(Vec3::Y, Vec3::Z).0 - FRAC_PI_2).abs() < 1e-6);
+        assert!((Vec3::X.signed_angle(Vec3::Y, -Vec3::Z).0 + FRAC_PI_2).abs() < 1e-6);
+        let rotated = a.rotate_around(b, Rad(-2.0));
+        let axis = a.cross(rotated);
+        assert!((a.signed_angle(rotated, axis) - a.angle(&rotated)).0.abs() < 1e-5);
+        // the axis only decides the sign, even if it isn't perpendicular to the vectors
+        let diagonal = Vec3::new(1.0, 1.0, 0.0);
+        let axis = Vec3::new(0.0, 1.0, 1.0);
+        assert!((Vec3::X.signed_angle(diagonal, axis).0 - FRAC_PI_4).abs() < 1e-6);
+        assert!((diagonal.signed_angle(Vec3::X, axis).0 + FRAC_PI_4).abs() < 1e-6);
+        assert!((a.signed_angle(rotated, axis + b).0.abs() - a.angle(&rotated).0).abs() < 1e-5);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn scalar_lhs_and_reference_ops() {
+        let v = Vec3::new(1.0, 2.0, 4.0);
+        assert_eq!(2.0 * v, v * 2.0);
+        assert_eq!(8.0 / v, Vec3::new(8.0, 4.0, 2.0));
+        assert_eq!(1.0 + v, Vec3::new(2.0, 3.0, 5.0));
+        assert_eq!(1.0 - v, Vec3::new(0.0, -1.0, -3.0));
+        assert_eq!(3u8 * vec2!(1u8, 2), vec2!(3u8, 6));
+        assert_eq!(
+            10i64 - &PolyVec4::new(1i64, 2, 3, 4),
+            PolyVec4::new(9, 8, 7, 6)
+        );
+        assert_eq!(0.5f64 * PolyVec2::new(2.0f64, 4.0), PolyVec2::new(1.0, 2.0));
+
+        let a = Vec4i::new(1, 2, 3, 4);
+        let b = Vec4i::new(4, 3, 2, 1);
+        assert_eq!(&a + &b, Vec4i::fill(5));
+        assert_eq!(&a * &b, Vec4i::new(4, 6, 6, 4));
+        let mut c = a;
+        c -= &b;
+        assert_eq!(c, Vec4i::new(-3, -1, 1, 3));
+
+        // doesn't require `Copy` components
+        fn add<T>(a: &PolyVec3<T>, b: &PolyVec3<T>) -> PolyVec3<T>
+        where
+            for<'a> &'a T: ops::Add<&'a T, Output = T>,
+        {
+            a + b
+        }
+        assert_eq!(add::<f32>(&v, &v), v * 2.0);
+    }
+
+    #[test]
+    fn integer_arithmetic() {
+        let a = Vec3i::new(-7, 5, i32::MAX);
+        let b = Vec3i::new(4, -3, 1);
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(a.checked_sub(b), Some(Vec3i::new(-11, 8, i32::MAX - 1)));
+        assert_eq!(b.checked_mul(b), Some(Vec3i::new(16, 9, 1)));
+        assert_eq!(a.checked_div(Vec3i::new(1, 0, 1)), None);
+        assert_eq!(a.wrapping_add(b), Vec3i::new(-3, 2, i32::MIN));
+        assert_eq!(a.saturating_add(b), Vec3i::new(-3, 2, i32::MAX));
+        assert_eq!(
+            Vec2u::new(3, 10).saturating_sub(Vec2u::new(5, 4)),
+            Vec2u::new(0, 6)
+        );
+        assert_eq!(
+            Vec2u::new(3, 10).wrapping_sub(Vec2u::new(5, 4)),
+            Vec2u::new(u32::MAX - 1, 6)
+        );
+
+        // chunk coordinates and the position inside of the chunk
+        let chunk = Vec3i::fill(16);
+        let p = Vec3i::new(-1, 17, -16);
+        assert_eq!(p.div_euclid(chunk), Vec3i::new(-1, 1, -1));
+        assert_eq!(p.rem_euclid(chunk), Vec3i::new(15, 1, 0));
+        assert_eq!(p.div_euclid(chunk) * chunk + p.rem_euclid(chunk), p);
+
+        assert_eq!(b.pow(3), Vec3i::new(64, -27, 1));
+        assert_eq!(
+            PolyVec2::new(i8::MIN, 3).abs_diff(PolyVec2::new(i8::MAX, -3)),
+            PolyVec2::new(255u8, 6)
+        );
+        assert_eq!(
+            Vec4u::new(1, 9, 0, 4).abs_diff(Vec4u::fill(4)),
+            Vec4u::new(3, 5, 4, 0)
+        );
+    }
+
+    #[test]
+    fn checked_conversions() {
+        let v: Result<Vec3b, _> = Vec3i::new(1, 255, 7).try_convert();
+        assert_eq!(v, Ok(vec3!(1u8, 255, 7)));
+        let err = TryConvert::<Vec3b>::try_convert(Vec3i::new(1, 256, -1)).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(
+            format!("{err}"),
+            "failed to convert component 1: out of range integral type conversion attempted"
+        );
+        let w: Result<PolyVec4<u16>, _> = PolyVec4::new(1u32, 2, 3, 70000).try_convert();
+        assert_eq!(w.unwrap_err().index, 3);
+
+        // `vec_into` wraps while `saturating_cast` clamps
+        let v: Vec3b = vec3![-1_i8, 100, 5].vec_into();
+        assert_eq!(v, vec3![255, 100, 5]);
+        let v: PolyVec3<i128> = PolyVec3::new(u128::MAX, 0, 1).saturating_cast();
+        assert_eq!(v, PolyVec3::new(i128::MAX, 0, 1));
+        let v: PolyVec4<i128> = Vec4::new(f32::NAN, f32::INFINITY, -3e38, -2.7).saturating_cast();
+        assert_eq!(v, PolyVec4::new(0, i128::MAX, i128::MIN, -2));
+        let v: Vec2 = PolyVec2::new(1e300_f64, -f64::INFINITY).saturating_cast();
+        assert_eq!(v, Vec2::new(f32::MAX, f32::MIN));
+        let v: Vec2 = PolyVec2::new(u128::MAX, 3).saturating_cast();
+        assert_eq!(v, Vec2::new(f32::MAX, 3.0));
+
+        let f = Vec4::new(-1.5, -0.4, 0.5, 2.6);
+        let r: PolyVec4<i128> = f.round_to_int();
+        assert_eq!(r, PolyVec4::new(-2, 0, 1, 3));
+        let r: PolyVec4<i128> = f.floor_to_int();
+        assert_eq!(r, PolyVec4::new(-2, -1, 0, 2));
+        let r: PolyVec4<i128> = f.ceil_to_int();
+        assert_eq!(r, PolyVec4::new(-1, 0, 1, 3));
+        let r: Vec2 = PolyVec2::new(7i32, -3).round_to_int();
+        assert_eq!(r, Vec2::new(7.0, -3.0));
+    }
+
+    #[test]
+    fn changing_dimensions() {
+        let v2 = Vec2::new(1.0, 2.0);
+        let v3 = v2.extend(3.0);
+        let v4 = v3.extend(4.0);
+        assert_eq!(v4, Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v4.truncate(), v3);
+        assert_eq!(v3.truncate(), v2);
+        assert_eq!(
+            Vec4::from_parts(v2, v2 * 2.0),
+            Vec4::new(1.0, 2.0, 2.0, 4.0)
+        );
+        assert_eq!(
+            Vec4::new(2.0, 4.0, -6.0, 2.0).xyz_div_w(),
+            Vec3::new(1.0, 2.0, -3.0)
+        );
+
+        assert_eq!(vec3![v2, 3.0], v3);
+        assert_eq!(vec4![v3, 4.0], v4);
+        assert_eq!(vec4![v2, Vec2::new(3.0, 4.0)], v4);
+        assert_eq!(vec4![v2, 3.0, 4.0], v4);
+        assert_eq!(vec4![7u8], Vec4b::fill(7));
+        assert_eq!(vec4![1, 2, ..], Vec4i::new(1, 2, 0, 0));
+        assert_eq!(vec3![Vec2i::new(1, 2), 3], Vec3i::new(1, 2, 3));
+    }
+
+    #[test]
+    fn masks_and_reductions() {
+        let a = Vec3::new(1.0, 5.0, 3.0);
+        let b = Vec3::new(2.0, 5.0, 1.0);
+        // the derived ordering is lexicographic
+        assert!(a < b);
+        assert_eq!(a.cmplt(b), PolyVec3::new(true, false, false));
+        assert_eq!(a.cmple(b), PolyVec3::new(true, true, false));
+        assert_eq!(a.cmpeq(b), PolyVec3::new(false, true, false));
+        assert_eq!(a.cmpne(b), PolyVec3::new(true, false, true));
+        assert_eq!(a.cmpgt(b), PolyVec3::new(false, false, true));
+        assert_eq!(a.cmpge(b), PolyVec3::new(false, true, true));
+
+        assert!(a.cmplt(b).any() && !a.cmplt(b).all() && !a.cmplt(b).none());
+        assert!(a.cmplt(a).none() && a.cmpeq(a).all());
+        assert_eq!(Vec3::select(a.cmplt(b), a, b), Vec3::new(1.0, 5.0, 1.0));
+
+        let v = Vec4i::new(4, -2, 9, -2);
+        assert_eq!(v.min_element(), -2);
+        assert_eq!(v.max_element(), 9);
+        assert_eq!(v.min_element_index(), (1, -2));
+        assert_eq!(v.max_element_index(), (2, 9));
+        assert_eq!(v.sum(), 9);
+        assert_eq!(v.product(), 144);
+
+        let nan = Vec3::new(f32::NAN, 2.0, -1.0);
+        assert_eq!(nan.min_element_index(), (2, -1.0));
+        assert_eq!(nan.max_element_index(), (1, 2.0));
+        assert!(Vec2::fill(f32::NAN).min_element().is_nan());
+    }
+
     #[cfg(feature = "binverse")]
     mod binverse_tests {
         use binverse::{
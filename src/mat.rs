@@ -1,6 +1,6 @@
 //! NOTE: matrices are in column-major order.
 
-use crate::{PolyVec3, Quaternion, Vec2, Vec3};
+use crate::{Convert, EulerOrder, PolyVec3, Quaternion, Rad, SVec, Vec2, Vec3, VecFrom};
 use core::{
     mem::MaybeUninit,
     ops::{Add, AddAssign, Index, IndexMut, Mul},
@@ -39,6 +39,28 @@ impl<T, const M: usize, const N: usize> Mat<T, M, N> {
             data: unsafe { data.assume_init() },
         }
     }
+
+    /// Applies the function to every element, returning a matrix of the results.
+    #[inline]
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Mat<U, M, N> {
+        Mat {
+            data: self.data.map(|column| column.map(&mut f)),
+        }
+    }
+
+    /// Combines the elements of two matrices of the same size using the function.
+    #[inline]
+    pub fn zip_map<U, V>(self, other: Mat<U, M, N>, mut f: impl FnMut(T, U) -> V) -> Mat<V, M, N> {
+        let mut a = self.data.into_iter();
+        let mut b = other.data.into_iter();
+        Mat {
+            data: core::array::from_fn(|_| {
+                let mut a = a.next().unwrap().into_iter();
+                let mut b = b.next().unwrap().into_iter();
+                core::array::from_fn(|_| f(a.next().unwrap(), b.next().unwrap()))
+            }),
+        }
+    }
 }
 
 impl<T, const M: usize, const N: usize> Index<usize> for Mat<T, M, N> {
@@ -70,8 +92,8 @@ impl<T: Zero + Copy + One, const N: usize> Mat<T, N, N> {
     #[inline]
     pub fn identity() -> Self {
         let mut data = [[T::zero(); N]; N];
-        for i in 0..N {
-            data[i][i] = T::one();
+        for (i, column) in data.iter_mut().enumerate() {
+            column[i] = T::one();
         }
         Self { data }
     }
@@ -86,10 +108,16 @@ impl<T: Zero + Copy + One, const N: usize> Mat<T, N, N> {
 }
 
 impl Mat<f32, 4, 4> {
+    /// Creates a perspective projection matrix with the vertical field of view `fov`.
     #[inline]
-    pub fn projection_matrix(viewport: Vec2, near_plane: f32, far_plane: f32, fov: f32) -> Self {
+    pub fn projection_matrix(
+        viewport: Vec2,
+        near_plane: f32,
+        far_plane: f32,
+        fov: impl Into<Rad<f32>>,
+    ) -> Self {
         let aspect_ratio = viewport.x / viewport.y;
-        let y_scale = 1.0 / (fov / 2.0).to_radians().tan();
+        let y_scale = 1.0 / (fov.into() / 2.0).tan();
         let x_scale = y_scale / aspect_ratio;
         let frustrum_length = far_plane - near_plane;
         Self::new([
@@ -143,26 +171,33 @@ impl Mat<f32, 4, 4> {
         self[1][1] *= scale.y;
         self[2][2] *= scale.z;
     }
+    /// A counter-clockwise rotation around the x axis, the same as
+    /// [`Quaternion::from_angle_around_axis`] with [`Vec3::X`].
     #[inline]
-    pub fn rx(r: f32) -> Self {
+    pub fn rx(r: impl Into<Rad<f32>>) -> Self {
+        let r = r.into().0;
         Self::new([
             [1.0, 0.0, 0.0, 0.0],
-            [0.0, r.cos(), r.sin(), 0.0],
-            [0.0, -r.sin(), r.cos(), 0.0],
+            [0.0, r.cos(), -r.sin(), 0.0],
+            [0.0, r.sin(), r.cos(), 0.0],
             [0.0, 0.0, 0.0, 1.0],
         ])
     }
+    /// A counter-clockwise rotation around the y axis, see [`rx`](Self::rx).
     #[inline]
-    pub fn ry(r: f32) -> Self {
+    pub fn ry(r: impl Into<Rad<f32>>) -> Self {
+        let r = r.into().0;
         Self::new([
-            [r.cos(), 0.0, -r.sin(), 0.0],
+            [r.cos(), 0.0, r.sin(), 0.0],
             [0.0, 1.0, 0.0, 0.0],
-            [r.sin(), 0.0, r.cos(), 0.0],
+            [-r.sin(), 0.0, r.cos(), 0.0],
             [0.0, 0.0, 0.0, 1.0],
         ])
     }
+    /// A counter-clockwise rotation around the z axis, see [`rx`](Self::rx).
     #[inline]
-    pub fn rz(r: f32) -> Self {
+    pub fn rz(r: impl Into<Rad<f32>>) -> Self {
+        let r = r.into().0;
         Self::new([
             [r.cos(), -r.sin(), 0.0, 0.0],
             [r.sin(), r.cos(), 0.0, 0.0],
@@ -170,9 +205,58 @@ impl Mat<f32, 4, 4> {
             [0.0, 0.0, 0.0, 1.0],
         ])
     }
+    /// Creates a rotation matrix from extrinsic euler angles, see
+    /// [`Quaternion::from_euler`].
+    #[inline]
+    pub fn from_euler(
+        order: EulerOrder,
+        a: impl Into<Rad<f32>>,
+        b: impl Into<Rad<f32>>,
+        c: impl Into<Rad<f32>>,
+    ) -> Self {
+        Quaternion::from_euler(order, a, b, c).matrix()
+    }
+    /// Creates a rotation matrix from intrinsic euler angles, see
+    /// [`Quaternion::from_euler_intrinsic`].
     #[inline]
-    pub fn rotate(&mut self, r: Vec3) {
-        *self = Self::rx(r.x) * Self::ry(r.y) * Self::rz(r.z) * *self;
+    pub fn from_euler_intrinsic(
+        order: EulerOrder,
+        a: impl Into<Rad<f32>>,
+        b: impl Into<Rad<f32>>,
+        c: impl Into<Rad<f32>>,
+    ) -> Self {
+        Quaternion::from_euler_intrinsic(order, a, b, c).matrix()
+    }
+    /// Calculates the extrinsic euler angles of the rotation part of the matrix, see
+    /// [`Quaternion::to_euler_angles`].
+    #[inline]
+    pub fn to_euler_angles(&self, order: EulerOrder) -> [Rad<f32>; 3] {
+        self.to_quaternion().to_euler_angles(order)
+    }
+    /// Calculates the intrinsic euler angles of the rotation part of the matrix, see
+    /// [`Quaternion::to_euler_angles_intrinsic`].
+    #[inline]
+    pub fn to_euler_angles_intrinsic(&self, order: EulerOrder) -> [Rad<f32>; 3] {
+        self.to_quaternion().to_euler_angles_intrinsic(order)
+    }
+    /// Extracts the rotation of the upper 3x3 part of the matrix. Scaling is removed.
+    #[inline]
+    pub fn to_quaternion(&self) -> Quaternion {
+        let column = |i: usize| Vec3::new(self[i][0], self[i][1], self[i][2]).normalized();
+        Quaternion::from_basis(column(0), column(1), column(2))
+    }
+    /// Applies the rotation `rx(x) * ry(y) * rz(z)`, which rotates around z first and x last.
+    /// This is the same as `from_euler(EulerOrder::ZYX, z, y, x)` and
+    /// `from_euler_intrinsic(EulerOrder::XYZ, x, y, z)`, see [`from_euler`](Self::from_euler)
+    /// for other orders.
+    #[inline]
+    pub fn rotate(
+        &mut self,
+        x: impl Into<Rad<f32>>,
+        y: impl Into<Rad<f32>>,
+        z: impl Into<Rad<f32>>,
+    ) {
+        *self = Self::rx(x) * Self::ry(y) * Self::rz(z) * *self;
     }
     #[inline]
     pub fn translate(&mut self, t: Vec3) {
@@ -245,6 +329,19 @@ impl<T: Zero + Copy + Add + Mul<Output = T>, const L: usize, const M: usize, con
     }
 }
 
+/// Multiplies the matrix with a column vector.
+impl<T: Zero + Copy + Add<Output = T> + Mul<Output = T>, const M: usize, const N: usize>
+    Mul<SVec<T, N>> for Mat<T, M, N>
+{
+    type Output = SVec<T, M>;
+    #[inline]
+    fn mul(self, v: SVec<T, N>) -> Self::Output {
+        SVec(core::array::from_fn(|m| {
+            (0..N).fold(T::zero(), |sum, n| sum + self[n][m] * v[n])
+        }))
+    }
+}
+
 impl<T: AddAssign> AddAssign<PolyVec3<T>> for Mat<T, 4, 4> {
     #[inline]
     fn add_assign(&mut self, v: PolyVec3<T>) {
@@ -254,6 +351,33 @@ impl<T: AddAssign> AddAssign<PolyVec3<T>> for Mat<T, 4, 4> {
     }
 }
 
+// conversions
+
+impl<T, U, const M: usize, const N: usize> Convert<Mat<U, M, N>> for Mat<T, M, N>
+where
+    T: Into<U>,
+{
+    #[inline]
+    fn convert(self) -> Mat<U, M, N> {
+        self.map(Into::into)
+    }
+}
+
+macro_rules! impl_mat_as {
+    ($($a: ty => $b: ty),*) => {
+        $(
+            impl<const M: usize, const N: usize> VecFrom<Mat<$a, M, N>> for Mat<$b, M, N> {
+                #[inline]
+                fn vec_from(a: Mat<$a, M, N>) -> Self {
+                    a.map(|x| x as $b)
+                }
+            }
+        )*
+    };
+}
+
+crate::vec::for_each_as_conversion!(impl_mat_as);
+
 // binverse serialization
 
 #[cfg(feature = "binverse")]
@@ -329,6 +453,7 @@ serde_mat! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Mat4x4, VecInto};
 
     #[test]
     fn matrix_multiplication() {
@@ -361,6 +486,68 @@ mod tests {
         assert_eq!(a.transpose(), Mat::new([[1, 4], [2, 5], [3, 6]]));
     }
 
+    #[test]
+    fn conversions() {
+        let a: Mat<i16, 2, 2> = Mat::new([[1, -2], [3, 4]]);
+        assert_eq!(a.map(|x| x * 2), Mat::new([[2, -4], [6, 8]]));
+        assert_eq!(
+            a.zip_map(Mat::new([[1, 1], [0, 2]]), |a, b| a * b),
+            Mat::new([[1, -2], [0, 8]])
+        );
+
+        let b: Mat<f32, 2, 2> = a.convert();
+        assert_eq!(b, Mat::new([[1.0, -2.0], [3.0, 4.0]]));
+
+        let c: Mat<f64, 4, 4> = Mat::identity();
+        let d: Mat4x4 = c.vec_into();
+        assert_eq!(d, Mat4x4::identity());
+
+        let e: Mat<u8, 2, 2> = a.vec_into();
+        assert_eq!(e, Mat::new([[1, 254], [3, 4]]));
+    }
+
+    #[test]
+    fn euler() {
+        let q = Quaternion::from_angle_around_axis(Vec3::new(1.0, -2.0, 0.5), Rad(2.1));
+        let mut scale = Mat4x4::identity();
+        scale.scale(Vec3::new(2.0, 0.5, 3.0));
+        let m = q.matrix() * scale;
+        assert!(m.to_quaternion().dot(q).abs() > 0.9999);
+
+        for order in EulerOrder::ALL {
+            let [a, b, c] = m.to_euler_angles(order);
+            let r = Mat4x4::from_euler(order, a, b, c);
+            let [a, b, c] = m.to_euler_angles_intrinsic(order);
+            let r_intrinsic = Mat4x4::from_euler_intrinsic(order, a, b, c);
+            for col in 0..4 {
+                for row in 0..4 {
+                    assert!((r[col][row] - q.matrix()[col][row]).abs() < 1e-4);
+                    assert!((r_intrinsic[col][row] - q.matrix()[col][row]).abs() < 1e-4);
+                }
+            }
+        }
+
+        let close = |a: Mat4x4, b: Mat4x4| {
+            (0..4).all(|col| (0..4).all(|row| (a[col][row] - b[col][row]).abs() < 1e-5))
+        };
+        for angle in [Rad(0.5), Rad(-2.0), crate::Deg(90.0).into()] {
+            let around = |axis| Quaternion::from_angle_around_axis(axis, angle).matrix();
+            assert!(close(Mat4x4::rx(angle), around(Vec3::X)));
+            assert!(close(Mat4x4::ry(angle), around(Vec3::Y)));
+            assert!(close(Mat4x4::rz(angle), around(Vec3::Z)));
+        }
+
+        let (x, y, z) = (crate::Deg(90.0), Rad(0.5), Rad(-1.0));
+        let mut rotated = Mat4x4::identity();
+        rotated.rotate(x, y, z);
+        assert_eq!(rotated, Mat4x4::rx(x) * Mat4x4::ry(y) * Mat4x4::rz(z));
+        assert!(close(rotated, Mat4x4::from_euler(EulerOrder::ZYX, z, y, x)));
+        assert!(close(
+            rotated,
+            Mat4x4::from_euler_intrinsic(EulerOrder::XYZ, x, y, z)
+        ));
+    }
+
     #[test]
     fn correct_drop_counts() {
         use core::sync::atomic::{AtomicU8, Ordering};
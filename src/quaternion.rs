@@ -1,5 +1,20 @@
-use crate::{Mat4x4, Vec3};
+mod spline;
 
+pub use spline::QuaternionSpline;
+
+use crate::{EulerOrder, Mat4x4, PolyVec4, Rad, Vec3, Vec4, W, X, Y, Z};
+use core::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A quaternion `w + xi + yj + zk`, mostly used to represent rotations.
+///
+/// NOTE: The fields are declared with `w` first, which is also the layout in memory and for
+/// serialization. GPU APIs and formats like glTF store quaternions as `[x, y, z, w]` instead.
+/// Indexing, swizzles and the conversions from/to [`PolyVec4`] use the `[x, y, z, w]` order,
+/// arrays can be converted explicitly using [`Quaternion::from_xyzw`]/[`Quaternion::to_xyzw`]
+/// and [`Quaternion::from_wxyz`]/[`Quaternion::to_wxyz`].
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quaternion {
@@ -10,19 +25,74 @@ pub struct Quaternion {
 }
 impl Default for Quaternion {
     fn default() -> Self {
-        Self {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-            w: 1.0,
-        }
+        Self::IDENTITY
     }
 }
 impl Quaternion {
-    /// Creates a Quaternion representing a rotation around the specified axis by an angle (in radians).
-    pub fn from_angle_around_axis(axis: Vec3, angle: f32) -> Self {
-        let a_2 = angle * 0.5;
-        let v = axis * a_2.sin();
+    /// The quaternion representing no rotation.
+    pub const IDENTITY: Self = Self {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// Creates a Quaternion from the real part `w` followed by the vector part `x, y, z`.
+    ///
+    /// Note that this is the opposite of [`Vec4::new`](crate::Vec4::new), which takes `w` last.
+    /// Use [`from_xyzw`](Self::from_xyzw) for data in `[x, y, z, w]` order.
+    #[inline]
+    pub const fn new(w: f32, x: f32, y: f32, z: f32) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// Creates a Quaternion from components in `[x, y, z, w]` order as used by GPU APIs and glTF.
+    /// Accepts arrays, tuples and vectors.
+    #[inline]
+    pub fn from_xyzw(xyzw: impl Into<[f32; 4]>) -> Self {
+        let [x, y, z, w] = xyzw.into();
+        Self { w, x, y, z }
+    }
+
+    /// Returns the components in `[x, y, z, w]` order as used by GPU APIs and glTF.
+    #[inline]
+    pub const fn to_xyzw(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Creates a Quaternion from components in `[w, x, y, z]` order, the order of the fields.
+    /// Accepts arrays, tuples and vectors.
+    #[inline]
+    pub fn from_wxyz(wxyz: impl Into<[f32; 4]>) -> Self {
+        let [w, x, y, z] = wxyz.into();
+        Self { w, x, y, z }
+    }
+
+    /// Returns the components in `[w, x, y, z]` order, the order of the fields.
+    #[inline]
+    pub const fn to_wxyz(self) -> [f32; 4] {
+        [self.w, self.x, self.y, self.z]
+    }
+
+    /// Returns the raw bits of the components in `[x, y, z, w]` order. Unlike `f32`, the bits
+    /// implement `Eq` and `Hash`, so they can be used as keys. Note that `0.0` and `-0.0` as well
+    /// as `q` and `-q` have different bits.
+    #[inline]
+    pub fn to_bits(self) -> [u32; 4] {
+        self.to_xyzw().map(f32::to_bits)
+    }
+
+    /// Creates a Quaternion from raw bits in `[x, y, z, w]` order, see [`Quaternion::to_bits`].
+    #[inline]
+    pub fn from_bits(bits: [u32; 4]) -> Self {
+        Self::from_xyzw(bits.map(f32::from_bits))
+    }
+
+    /// Creates a Quaternion representing a rotation around the specified axis by an angle.
+    /// The axis doesn't have to be normalized.
+    pub fn from_angle_around_axis(axis: Vec3, angle: impl Into<Rad<f32>>) -> Self {
+        let a_2 = angle.into().0 * 0.5;
+        let v = axis.normalized() * a_2.sin();
         Self {
             w: a_2.cos(),
             x: v.x,
@@ -31,8 +101,83 @@ impl Quaternion {
         }
     }
 
+    /// Creates a Quaternion from a rotation vector (axis multiplied by the angle in radians).
+    pub fn from_scaled_axis(v: Vec3) -> Self {
+        let angle = v.magnitude();
+        if angle == 0.0 {
+            return Self::IDENTITY;
+        }
+        Self::from_angle_around_axis(v / angle, Rad(angle))
+    }
+
+    /// Creates the shortest rotation that rotates the direction `from` onto the direction `to`.
+    /// The vectors don't have to be normalized.
+    pub fn from_rotation_arc(from: Vec3, to: Vec3) -> Self {
+        let from = from.normalized();
+        let to = to.normalized();
+        let d = from.dot(to);
+        if d < -1.0 + 1e-6 {
+            // the vectors point in opposite directions so any axis orthogonal to them works
+            let mut axis = Vec3::X.cross(from);
+            if axis.square_magnitude() < 1e-6 {
+                axis = Vec3::Y.cross(from);
+            }
+            return Self::from_angle_around_axis(axis, Rad(core::f32::consts::PI));
+        }
+        let v = from.cross(to);
+        Self {
+            w: 1.0 + d,
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+        .normalized()
+    }
+
+    /// Creates the rotation that maps the x, y and z axes onto the given orthonormal basis vectors.
+    pub fn from_basis(x: Vec3, y: Vec3, z: Vec3) -> Self {
+        // Source: https://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/
+        let trace = x.x + y.y + z.z;
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self {
+                w: 0.25 * s,
+                x: (y.z - z.y) / s,
+                y: (z.x - x.z) / s,
+                z: (x.y - y.x) / s,
+            }
+        } else if x.x > y.y && x.x > z.z {
+            let s = (1.0 + x.x - y.y - z.z).sqrt() * 2.0;
+            Self {
+                w: (y.z - z.y) / s,
+                x: 0.25 * s,
+                y: (y.x + x.y) / s,
+                z: (z.x + x.z) / s,
+            }
+        } else if y.y > z.z {
+            let s = (1.0 + y.y - x.x - z.z).sqrt() * 2.0;
+            Self {
+                w: (z.x - x.z) / s,
+                x: (y.x + x.y) / s,
+                y: 0.25 * s,
+                z: (z.y + y.z) / s,
+            }
+        } else {
+            let s = (1.0 + z.z - x.x - y.y).sqrt() * 2.0;
+            Self {
+                w: (x.y - y.x) / s,
+                x: (z.x + x.z) / s,
+                y: (z.y + y.z) / s,
+                z: 0.25 * s,
+            }
+        };
+        q.normalized()
+    }
+
     /// Creates a rotation Quaternion from euler angles in 3-2-1 order.
-    pub fn euler(x: f32, y: f32, z: f32) -> Self {
+    /// This is the same as [`Quaternion::from_euler`] with [`EulerOrder::XYZ`].
+    pub fn euler(x: impl Into<Rad<f32>>, y: impl Into<Rad<f32>>, z: impl Into<Rad<f32>>) -> Self {
+        let (x, y, z) = (x.into().0, y.into().0, z.into().0);
         // Source: https://en.wikipedia.org/wiki/Conversion_between_quaternions_and_Euler_angles#Source_code
         let cr = (x * 0.5).cos();
         let sr = (x * 0.5).sin();
@@ -49,8 +194,9 @@ impl Quaternion {
         }
     }
 
-    #[must_use = "only calculates the euler vector"]
-    pub fn to_euler(self) -> Vec3 {
+    /// Calculates the euler angles in 3-2-1 order, see [`Quaternion::euler`].
+    #[must_use = "only calculates the euler angles"]
+    pub fn to_euler(self) -> [Rad<f32>; 3] {
         // Source: https://en.wikipedia.org/wiki/Conversion_between_quaternions_and_Euler_angles#Source_code_2
 
         // roll (x-axis rotation)
@@ -68,7 +214,250 @@ impl Quaternion {
         let cosy_cosp = 1.0 - 2.0 * (self.y * self.y + self.z * self.z);
         let z = f32::atan2(siny_cosp, cosy_cosp);
 
-        Vec3 { x, y, z }
+        [Rad(x), Rad(y), Rad(z)]
+    }
+
+    /// Creates a rotation from extrinsic euler angles. The angles `a`, `b` and `c`
+    /// rotate around the first, second and third axis of the order. All rotations are around the
+    /// fixed world axes.
+    pub fn from_euler(
+        order: EulerOrder,
+        a: impl Into<Rad<f32>>,
+        b: impl Into<Rad<f32>>,
+        c: impl Into<Rad<f32>>,
+    ) -> Self {
+        let [a, b, c] = Self::euler_rotations(order, [a.into(), b.into(), c.into()]);
+        c * b * a
+    }
+
+    /// Creates a rotation from intrinsic euler angles. The angles `a`, `b` and `c`
+    /// rotate around the first, second and third axis of the order. Each rotation is around the
+    /// axes already rotated by the previous rotations.
+    pub fn from_euler_intrinsic(
+        order: EulerOrder,
+        a: impl Into<Rad<f32>>,
+        b: impl Into<Rad<f32>>,
+        c: impl Into<Rad<f32>>,
+    ) -> Self {
+        let [a, b, c] = Self::euler_rotations(order, [a.into(), b.into(), c.into()]);
+        a * b * c
+    }
+
+    fn euler_rotations(order: EulerOrder, angles: [Rad<f32>; 3]) -> [Self; 3] {
+        let axes = order.axes();
+        core::array::from_fn(|i| {
+            Self::from_angle_around_axis([Vec3::X, Vec3::Y, Vec3::Z][axes[i]], angles[i])
+        })
+    }
+
+    /// Calculates extrinsic euler angles in the given order, see
+    /// [`Quaternion::from_euler`]. All angles are in the range [-pi, pi].
+    ///
+    /// In gimbal lock, only the sum or difference of the first and third angle is defined. The
+    /// third angle is set to zero in that case.
+    #[must_use = "only calculates the euler angles"]
+    pub fn to_euler_angles(self, order: EulerOrder) -> [Rad<f32>; 3] {
+        self.euler_angles(order, true).map(Rad)
+    }
+
+    /// Calculates intrinsic euler angles in the given order, see
+    /// [`Quaternion::from_euler_intrinsic`]. All angles are in the range [-pi, pi].
+    ///
+    /// In gimbal lock, only the sum or difference of the first and third angle is defined. The
+    /// third angle is set to zero in that case.
+    #[must_use = "only calculates the euler angles"]
+    pub fn to_euler_angles_intrinsic(self, order: EulerOrder) -> [Rad<f32>; 3] {
+        self.euler_angles(order, false).map(Rad)
+    }
+
+    fn euler_angles(self, order: EulerOrder, extrinsic: bool) -> [f32; 3] {
+        // Source: Bernardes, E., Viollet, S. (2022). Quaternion to Euler angles conversion: A direct,
+        // general and computationally efficient method.
+        // The method works on extrinsic angles, intrinsic angles are the reversed extrinsic ones.
+        use core::f32::consts::{FRAC_PI_2, PI};
+        const GIMBAL_LOCK_EPSILON: f32 = 1e-6;
+
+        let order = if extrinsic { order } else { order.reversed() };
+        let [i, j, k] = order.axes();
+        let proper = order.is_proper();
+        let k = if proper { 3 - i - j } else { k };
+        // 1 for even permutations of the axes, -1 for odd permutations
+        let sign = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+
+        let v = [self.x, self.y, self.z];
+        let (a, b, c, d) = if proper {
+            (self.w, v[i], v[j], v[k] * sign)
+        } else {
+            (
+                self.w - v[j],
+                v[i] + v[k] * sign,
+                v[j] + self.w,
+                v[k] * sign - v[i],
+            )
+        };
+
+        let mut angles = [0.0; 3];
+        angles[1] = 2.0 * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+        let lock_zero = angles[1].abs() < GIMBAL_LOCK_EPSILON;
+        let lock_pi = (angles[1] - PI).abs() < GIMBAL_LOCK_EPSILON;
+        if !lock_zero && !lock_pi {
+            angles[0] = half_sum - half_diff;
+            angles[2] = half_sum + half_diff;
+        } else if extrinsic {
+            angles[0] = if lock_zero {
+                2.0 * half_sum
+            } else {
+                -2.0 * half_diff
+            };
+        } else {
+            // the angles get reversed, so this sets the third intrinsic angle to zero
+            angles[2] = if lock_zero {
+                2.0 * half_sum
+            } else {
+                2.0 * half_diff
+            };
+        }
+        if !proper {
+            angles[2] *= sign;
+            angles[1] -= FRAC_PI_2;
+        }
+        if !extrinsic {
+            angles.swap(0, 2);
+        }
+        angles.map(|angle| {
+            if angle < -PI {
+                angle + 2.0 * PI
+            } else if angle > PI {
+                angle - 2.0 * PI
+            } else {
+                angle
+            }
+        })
+    }
+
+    /// Retrieves the normalized rotation axis and the angle of a unit quaternion. The identity
+    /// rotation returns the x axis and an angle of zero.
+    #[must_use = "only calculates the axis and angle"]
+    pub fn to_axis_angle(self) -> (Vec3, Rad<f32>) {
+        let l = self.v().magnitude();
+        if l == 0.0 {
+            return (Vec3::X, Rad(0.0));
+        }
+        (self.v() / l, Rad(2.0 * l.atan2(self.w)))
+    }
+
+    /// Retrieves the rotation vector (axis multiplied by the angle in radians) of a unit
+    /// quaternion. The angle is in the range [0, pi] so the shortest rotation is returned.
+    #[must_use = "only calculates the rotation vector"]
+    pub fn to_scaled_axis(self) -> Vec3 {
+        let q = if self.w < 0.0 { -self } else { self };
+        let (axis, angle) = q.to_axis_angle();
+        axis * angle.0
+    }
+
+    /// Returns the angle of the smallest rotation between two unit quaternions.
+    #[must_use = "only calculates the angle"]
+    pub fn angle_between(self, other: Self) -> Rad<f32> {
+        Rad(2.0 * self.dot(other).abs().min(1.0).acos())
+    }
+
+    /// Integrates the orientation over the timestep `dt` with a constant world-space angular
+    /// velocity (in radians per unit of time). This uses the exponential map, so the result is
+    /// exact for any timestep.
+    #[must_use = "returns the integrated quaternion"]
+    pub fn integrate(self, angular_velocity: Vec3, dt: f32) -> Self {
+        let angle = angular_velocity.magnitude() * dt;
+        if angle == 0.0 {
+            return self;
+        }
+        (Self::from_angle_around_axis(angular_velocity, Rad(angle)) * self).normalized()
+    }
+
+    /// Integrates the orientation over the timestep `dt` with a constant world-space angular
+    /// velocity (in radians per unit of time) using the first-order approximation
+    /// `q' = 0.5 * ω * q` followed by a renormalization. This is cheaper than
+    /// [`Quaternion::integrate`] but only accurate for small rotations per step.
+    #[must_use = "returns the integrated quaternion"]
+    pub fn integrate_first_order(self, angular_velocity: Vec3, dt: f32) -> Self {
+        let omega = Self {
+            w: 0.0,
+            x: angular_velocity.x,
+            y: angular_velocity.y,
+            z: angular_velocity.z,
+        };
+        (self + omega * self * (0.5 * dt)).normalized()
+    }
+
+    /// Calculates the constant world-space angular velocity that rotates `q0` to `q1` in the
+    /// timestep `dt` along the shortest path. This is the inverse of [`Quaternion::integrate`].
+    #[must_use = "only calculates the angular velocity"]
+    pub fn angular_velocity_between(q0: Self, q1: Self, dt: f32) -> Vec3 {
+        (q1 * q0.conjugate()).to_scaled_axis() / dt
+    }
+
+    /// Decomposes the rotation into a swing and a twist so that `self = swing * twist`.
+    /// The twist is the part of the rotation around `axis`, the swing rotates around an axis
+    /// perpendicular to `axis`. The axis doesn't have to be normalized.
+    #[must_use = "only calculates the swing and twist"]
+    pub fn swing_twist(self, axis: Vec3) -> (Self, Self) {
+        let axis = axis.normalized();
+        let p = axis * self.v().dot(axis);
+        let twist = Self {
+            w: self.w,
+            x: p.x,
+            y: p.y,
+            z: p.z,
+        };
+        if twist.dot(twist) < 1e-12 {
+            // a half turn perpendicular to the axis, there is no twist
+            return (self, Self::IDENTITY);
+        }
+        let twist = twist.normalized();
+        (self * twist.conjugate(), twist)
+    }
+
+    /// Returns the signed angle of the rotation around `axis` in the range [-pi, pi]. This is the
+    /// angle of the twist from [`Quaternion::swing_twist`].
+    #[must_use = "only calculates the angle"]
+    pub fn twist_angle(self, axis: Vec3) -> Rad<f32> {
+        let axis = axis.normalized();
+        let (_, twist) = self.swing_twist(axis);
+        let twist = if twist.w < 0.0 { -twist } else { twist };
+        Rad(2.0 * twist.v().dot(axis).atan2(twist.w))
+    }
+
+    /// Limits the angle of the rotation to `max_angle` while keeping the rotation axis. The
+    /// shortest rotation is used so the result never rotates more than half a turn.
+    #[must_use = "returns the clamped quaternion"]
+    pub fn clamp_angle(self, max_angle: impl Into<Rad<f32>>) -> Self {
+        let max_angle = max_angle.into();
+        let q = if self.w < 0.0 { -self } else { self };
+        let (axis, angle) = q.to_axis_angle();
+        if angle <= max_angle {
+            return q;
+        }
+        Self::from_angle_around_axis(axis, max_angle)
+    }
+
+    /// Applies a cone-and-twist joint limit. The swing is limited to a cone of `max_swing`
+    /// around `axis` and the twist around `axis` is clamped to the range
+    /// `[min_twist, max_twist]` before recomposing the rotation.
+    #[must_use = "returns the limited quaternion"]
+    pub fn clamp_swing_twist(
+        self,
+        axis: Vec3,
+        max_swing: impl Into<Rad<f32>>,
+        min_twist: impl Into<Rad<f32>>,
+        max_twist: impl Into<Rad<f32>>,
+    ) -> Self {
+        let (swing, _) = self.swing_twist(axis);
+        let twist = self
+            .twist_angle(axis)
+            .0
+            .clamp(min_twist.into().0, max_twist.into().0);
+        swing.clamp_angle(max_swing) * Self::from_angle_around_axis(axis, Rad(twist))
     }
 
     /// Retrieves the vector-part of the Quaternion: [x, y, z]
@@ -143,11 +532,271 @@ impl Quaternion {
         }
     }
 
+    /// Returns the inverse rotation. Unlike [`Quaternion::conjugate`], this also works for
+    /// quaternions that aren't unit-length.
+    #[must_use = "returns the new, inverted quaternion"]
+    pub fn inverse(self) -> Self {
+        self.conjugate() / self.dot(self)
+    }
+
+    /// The quaternion exponential.
+    #[must_use = "only calculates the exponential"]
+    pub fn exp(self) -> Self {
+        let angle = self.v().magnitude();
+        let e = self.w.exp();
+        if angle < 1e-6 {
+            // sin(angle) / angle approaches 1
+            return Self {
+                w: e,
+                x: e * self.x,
+                y: e * self.y,
+                z: e * self.z,
+            };
+        }
+        let v = self.v() * (e * angle.sin() / angle);
+        Self {
+            w: e * angle.cos(),
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+
+    /// The natural logarithm of the quaternion.
+    ///
+    /// Negative real quaternions have infinitely many logarithms with a vector part of length pi.
+    /// In that case, the one with the vector part along the x axis is returned.
+    #[must_use = "only calculates the logarithm"]
+    pub fn ln(self) -> Self {
+        let l = self.v().magnitude();
+        let w = self.length().ln();
+        if l == 0.0 {
+            return Self {
+                w,
+                x: if self.w < 0.0 {
+                    core::f32::consts::PI
+                } else {
+                    0.0
+                },
+                y: 0.0,
+                z: 0.0,
+            };
+        }
+        let v = self.v() * (l.atan2(self.w) / l);
+        Self {
+            w,
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+
+    /// Raises the quaternion to a real power. For unit quaternions, this scales the rotation
+    /// angle by `t`.
+    #[must_use = "only calculates the power"]
+    pub fn powf(self, t: f32) -> Self {
+        (self.ln() * t).exp()
+    }
+
+    /// Rotates the vector by this quaternion.
+    /// NOTE: Assumes a unit-length Quaternion.
+    #[must_use = "only calculates the rotated vector"]
+    pub fn rotate_vec3(self, v: Vec3) -> Vec3 {
+        self.debug_assert_normalized();
+        // Equivalent to (q * v * q^-1).v() but with less operations:
+        // v + 2w(q × v) + 2q × (q × v)
+        // The normalized Quaternion is assumed since an inversion would be needed instead of a
+        // conjugation otherwise.
+        let t = self.v().cross(v) * 2.0;
+        v + t * self.w + self.v().cross(t)
+    }
+
+    #[inline]
+    fn debug_assert_normalized(self) {
+        #[cfg(debug_assertions)]
+        {
+            // Checks for approximate unit-length since chained calculations might make Quaternions
+            // slightly non-normalized. This should catch cases where the Quaternion isn't
+            // normalized at all.
+            let len = self.length();
+            debug_assert!(
+                (0.9..=1.1).contains(&len),
+                "Quaternion-Vector multiplication should be used with a normalized Quaternion.",
+            );
+        }
+    }
+
+    /// Normalized linear interpolation. This is cheaper than [`Quaternion::slerp`] but doesn't
+    /// interpolate with a constant angular velocity. Takes the shortest path between the rotations.
+    #[must_use = "returns the interpolated quaternion"]
+    pub fn nlerp(self, other: Self, t: f32) -> Self {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        (self * (1.0 - t) + other * t).normalized()
+    }
+
+    /// Spherical linear interpolation between two unit quaternions. Interpolates with a constant
+    /// angular velocity along the shortest path between the rotations.
+    #[must_use = "returns the interpolated quaternion"]
     pub fn slerp(self, other: Self, t: f32) -> Self {
-        let o = (self.dot(other)).acos();
-        ((1.0 - t).sin() * o) / o.sin() * self + (t * o).sin() / o.sin() * other
+        // below this angle, sin(o) gets too close to zero and nlerp is indistinguishable from slerp
+        const NLERP_THRESHOLD: f32 = 1e-3;
+
+        let mut cos_o = self.dot(other);
+        // q and -q represent the same rotation, flip one of them to take the shortest path
+        let other = if cos_o < 0.0 {
+            cos_o = -cos_o;
+            -other
+        } else {
+            other
+        };
+        if cos_o.min(1.0).acos() < NLERP_THRESHOLD {
+            return self.nlerp(other, t);
+        }
+        self.slerp_direct(other, t)
+    }
+
+    /// slerp without taking the shortest path
+    fn slerp_direct(self, other: Self, t: f32) -> Self {
+        let o = self.dot(other).clamp(-1.0, 1.0).acos();
+        let sin_o = o.sin();
+        if sin_o.abs() < 1e-6 {
+            return (self * (1.0 - t) + other * t).normalized();
+        }
+        (((1.0 - t) * o).sin() / sin_o) * self + ((t * o).sin() / sin_o) * other
+    }
+
+    /// Spherical cubic interpolation between `self` and `other` using the control points `a` and
+    /// `b` from [`Quaternion::squad_control_point`]. The resulting curve has a continuous angular
+    /// velocity through keyframes when the control points of neighboring keyframes are used.
+    ///
+    /// All quaternions are expected to be unit length and in the same hemisphere as their
+    /// neighbors, [`QuaternionSpline`] takes care of this for a sequence of keyframes.
+    #[must_use = "returns the interpolated quaternion"]
+    pub fn squad(self, other: Self, a: Self, b: Self, t: f32) -> Self {
+        self.slerp_direct(other, t)
+            .slerp_direct(a.slerp_direct(b, t), 2.0 * t * (1.0 - t))
+    }
+
+    /// Calculates the intermediate control point of the keyframe `current` for
+    /// [`Quaternion::squad`] using the neighboring keyframes.
+    /// `prev` and `next` are flipped into the hemisphere of `current` if necessary.
+    #[must_use = "only calculates the control point"]
+    pub fn squad_control_point(prev: Self, current: Self, next: Self) -> Self {
+        let prev = if prev.dot(current) < 0.0 { -prev } else { prev };
+        let next = if next.dot(current) < 0.0 { -next } else { next };
+        let inv = current.conjugate();
+        current * (((inv * next).ln() + (inv * prev).ln()) * -0.25).exp()
+    }
+}
+
+// ---------- Component traits ----------
+
+impl X<f32> for Quaternion {
+    #[inline(always)]
+    fn x(&self) -> &f32 {
+        &self.x
+    }
+    fn set_x(&mut self, x: f32) {
+        self.x = x;
+    }
+}
+impl Y<f32> for Quaternion {
+    #[inline(always)]
+    fn y(&self) -> &f32 {
+        &self.y
+    }
+    fn set_y(&mut self, y: f32) {
+        self.y = y;
+    }
+}
+impl Z<f32> for Quaternion {
+    #[inline(always)]
+    fn z(&self) -> &f32 {
+        &self.z
+    }
+    fn set_z(&mut self, z: f32) {
+        self.z = z;
     }
 }
+impl W<f32> for Quaternion {
+    #[inline(always)]
+    fn w(&self) -> &f32 {
+        &self.w
+    }
+    fn set_w(&mut self, w: f32) {
+        self.w = w;
+    }
+}
+
+// ---------- indexing ----------
+
+/// Indexes the components in `[x, y, z, w]` order like [`PolyVec4`].
+impl Index<usize> for Quaternion {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("index {index} out of range for Quaternion"),
+        }
+    }
+}
+impl IndexMut<usize> for Quaternion {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("index {index} out of range for Quaternion"),
+        }
+    }
+}
+
+// ---------- conversions ----------
+
+impl From<Vec4> for Quaternion {
+    /// Converts by component name, so `w` becomes the real part.
+    fn from(v: Vec4) -> Self {
+        Self {
+            w: v.w,
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+impl From<Quaternion> for Vec4 {
+    /// Converts by component name, so the real part becomes `w`.
+    fn from(q: Quaternion) -> Self {
+        PolyVec4 {
+            x: q.x,
+            y: q.y,
+            z: q.z,
+            w: q.w,
+        }
+    }
+}
+
+// ---------- display ----------
+
+impl fmt::Display for Quaternion {
+    /// Displays the quaternion as `w + xi + yj + zk`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.w, f)?;
+        for (v, unit) in [(self.x, 'i'), (self.y, 'j'), (self.z, 'k')] {
+            f.write_str(if v.is_sign_negative() { " - " } else { " + " })?;
+            fmt::Display::fmt(&v.abs(), f)?;
+            write!(f, "{unit}")?;
+        }
+        Ok(())
+    }
+}
+
+// ---------- operators ----------
 
 impl core::ops::Mul<Self> for Quaternion {
     type Output = Self;
@@ -162,6 +811,37 @@ impl core::ops::Mul<Self> for Quaternion {
         }
     }
 }
+impl core::ops::MulAssign<Self> for Quaternion {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl core::ops::Div<Self> for Quaternion {
+    type Output = Self;
+
+    /// Multiplies with the inverse of `rhs`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+impl core::ops::DivAssign<Self> for Quaternion {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+impl core::ops::Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            w: -self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
 impl core::ops::Add<Self> for Quaternion {
     type Output = Self;
 
@@ -226,31 +906,39 @@ impl core::ops::MulAssign<f32> for Quaternion {
         self.z *= rhs;
     }
 }
+impl core::ops::Div<f32> for Quaternion {
+    type Output = Self;
+
+    fn div(mut self, rhs: f32) -> Self::Output {
+        self.w /= rhs;
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self
+    }
+}
+impl core::ops::DivAssign<f32> for Quaternion {
+    fn div_assign(&mut self, rhs: f32) {
+        self.w /= rhs;
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+impl core::ops::Mul<Vec3> for Quaternion {
+    type Output = Vec3;
+    /// Rotates the vector. See [`Quaternion::rotate_vec3`].
+    /// NOTE: Assumes a unit-length Quaternion.
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.rotate_vec3(rhs)
+    }
+}
 impl core::ops::Mul<Quaternion> for Vec3 {
     type Output = Vec3;
+    /// Same as `Quaternion * Vec3`.
     /// NOTE: Assumes a unit-length Quaternion.
     fn mul(self, rhs: Quaternion) -> Self::Output {
-        #[cfg(debug_assertions)]
-        {
-            // Checks for approximate unit-length since chained calculations might make Quaternions
-            // slightly non-normalized. This should catch cases where the Quaternion isn't
-            // normalized at all.
-            let len = rhs.length();
-            debug_assert!(
-                (0.9..=1.1).contains(&len),
-                "Quaternion-Vector multiplication should be used with a normalized Quaternion.",
-            );
-            debug_assert!(len <= 1.1);
-        }
-        let p = Quaternion {
-            w: 0.0,
-            x: self.x,
-            y: self.y,
-            z: self.z,
-        };
-        // This is where the normalized Quaternion is assumed since an inversion would be needed
-        // instead of a conjugation otherwise.
-        (rhs * p * rhs.conjugate()).v()
+        rhs.rotate_vec3(self)
     }
 }
 
@@ -281,3 +969,379 @@ impl<R: std::io::Read> binverse::serialize::Deserialize<R> for Quaternion {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_vec_eq, random_rotation};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    const EPSILON: f32 = 1e-4;
+
+    fn assert_same_rotation(a: Quaternion, b: Quaternion) {
+        assert!(
+            a.dot(b).abs() > 1.0 - EPSILON,
+            "{a:?} and {b:?} are different rotations"
+        );
+    }
+
+    #[test]
+    fn slerp_unit_length() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let (a, b) = (random_rotation(&mut rng), random_rotation(&mut rng));
+            let t = rng.gen_range(0.0..=1.0);
+            assert!((a.slerp(b, t).length() - 1.0).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn slerp_endpoints() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..1000 {
+            let (a, b) = (random_rotation(&mut rng), random_rotation(&mut rng));
+            assert_same_rotation(a.slerp(b, 0.0), a);
+            assert_same_rotation(a.slerp(b, 1.0), b);
+        }
+    }
+
+    #[test]
+    fn slerp_constant_angular_velocity() {
+        const STEPS: usize = 10;
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..200 {
+            let (a, b) = (random_rotation(&mut rng), random_rotation(&mut rng));
+            let total = Quaternion::angle_between(a, b).0;
+            // shortest path means never rotating more than half a turn
+            assert!(total <= core::f32::consts::PI + EPSILON);
+            let mut prev = a;
+            for i in 1..=STEPS {
+                let q = a.slerp(b, i as f32 / STEPS as f32);
+                let step = Quaternion::angle_between(prev, q).0;
+                assert!(
+                    (step - total / STEPS as f32).abs() < 1e-3,
+                    "step {i} rotated by {step} instead of {}",
+                    total / STEPS as f32
+                );
+                prev = q;
+            }
+        }
+    }
+
+    #[test]
+    fn slerp_nearly_identical() {
+        let a = Quaternion::from_angle_around_axis(Vec3::Y, Rad(0.3));
+        let b = Quaternion::from_angle_around_axis(Vec3::Y, Rad(0.3 + 1e-6));
+        let q = a.slerp(b, 0.5);
+        assert!(q.w.is_finite() && q.x.is_finite() && q.y.is_finite() && q.z.is_finite());
+        assert_same_rotation(q, a);
+        assert_same_rotation(a.slerp(a, 0.5), a);
+    }
+
+    #[test]
+    fn slerp_shortest_path() {
+        let a = Quaternion::from_angle_around_axis(Vec3::Z, Rad(0.1));
+        let b = -Quaternion::from_angle_around_axis(Vec3::Z, Rad(0.3));
+        assert_same_rotation(
+            a.slerp(b, 0.5),
+            Quaternion::from_angle_around_axis(Vec3::Z, Rad(0.2)),
+        );
+    }
+
+    #[test]
+    fn rotation_arc() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..1000 {
+            let from = random_rotation(&mut rng).v() * 3.0;
+            let to = random_rotation(&mut rng).v();
+            let q = Quaternion::from_rotation_arc(from, to);
+            assert!((q.length() - 1.0).abs() < EPSILON);
+            assert_vec_eq(from.normalized() * q, to.normalized(), 1e-3);
+        }
+        for v in [Vec3::X, Vec3::Y, Vec3::Z, Vec3::new(1.0, -2.0, 0.5)] {
+            let q = Quaternion::from_rotation_arc(v, -v);
+            assert!((q.length() - 1.0).abs() < EPSILON);
+            assert_vec_eq(v * q, -v, 1e-3);
+            assert_same_rotation(Quaternion::from_rotation_arc(v, v), Quaternion::IDENTITY);
+        }
+    }
+
+    #[test]
+    fn axis_angle_round_trip() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..1000 {
+            let q = random_rotation(&mut rng);
+            let (axis, angle) = q.to_axis_angle();
+            assert!((axis.magnitude() - 1.0).abs() < EPSILON);
+            assert_same_rotation(Quaternion::from_angle_around_axis(axis, angle), q);
+
+            let v = q.to_scaled_axis();
+            assert!(v.magnitude() <= core::f32::consts::PI + EPSILON);
+            assert!((v.magnitude() - q.angle_between(Quaternion::IDENTITY).0).abs() < 1e-3);
+            assert_same_rotation(Quaternion::from_scaled_axis(v), q);
+        }
+        assert_eq!(
+            Quaternion::from_scaled_axis(Vec3::ZERO),
+            Quaternion::IDENTITY
+        );
+        assert_eq!(Quaternion::IDENTITY.to_scaled_axis(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn basis() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..1000 {
+            let q = random_rotation(&mut rng);
+            let b = Quaternion::from_basis(Vec3::X * q, Vec3::Y * q, Vec3::Z * q);
+            assert!((b.length() - 1.0).abs() < EPSILON);
+            assert_same_rotation(b, q);
+        }
+    }
+
+    #[test]
+    fn unnormalized_axis() {
+        let q = Quaternion::from_angle_around_axis(Vec3::new(0.0, 5.0, 0.0), Rad(1.0));
+        assert!((q.length() - 1.0).abs() < EPSILON);
+        assert_same_rotation(q, Quaternion::from_angle_around_axis(Vec3::Y, Rad(1.0)));
+    }
+
+    fn assert_quat_eq(a: Quaternion, b: Quaternion) {
+        assert!((a - b).length() < 1e-3, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn inverse_and_division() {
+        let mut rng = StdRng::seed_from_u64(6);
+        for _ in 0..1000 {
+            let q = random_rotation(&mut rng) * rng.gen_range(0.5..2.0);
+            let p = random_rotation(&mut rng);
+            assert_quat_eq(q * q.inverse(), Quaternion::IDENTITY);
+            assert_quat_eq(q.inverse() * q, Quaternion::IDENTITY);
+            assert_quat_eq((p / q) * q, p);
+            assert_quat_eq(q / 2.0 * 2.0, q);
+        }
+    }
+
+    #[test]
+    fn exp_ln_pow() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..1000 {
+            let q = random_rotation(&mut rng);
+            assert_quat_eq(q.ln().exp(), q);
+            assert!(q.ln().w.abs() < EPSILON);
+            assert_quat_eq(q.powf(2.0), q * q);
+            assert_quat_eq(q.powf(0.0), Quaternion::IDENTITY);
+            assert_quat_eq(q.powf(1.0), q);
+            let (axis, angle) = q.to_axis_angle();
+            assert_same_rotation(
+                q.powf(0.5),
+                Quaternion::from_angle_around_axis(axis, angle * 0.5),
+            );
+        }
+        assert_eq!(Quaternion::IDENTITY.ln().length(), 0.0);
+
+        let neg = -Quaternion::IDENTITY;
+        let ln = neg.ln();
+        assert_eq!(ln.w, 0.0);
+        assert!((ln.v().magnitude() - core::f32::consts::PI).abs() < EPSILON);
+        assert_quat_eq(ln.exp(), neg);
+        assert_quat_eq((neg * 2.0).ln().exp(), neg * 2.0);
+        assert_quat_eq(neg.powf(0.5) * neg.powf(0.5), neg);
+    }
+
+    #[test]
+    fn rotate_vector() {
+        let mut rng = StdRng::seed_from_u64(8);
+        for _ in 0..1000 {
+            let q = random_rotation(&mut rng);
+            let v = random_rotation(&mut rng).v() * 4.0;
+            let p = Quaternion {
+                w: 0.0,
+                x: v.x,
+                y: v.y,
+                z: v.z,
+            };
+            let expected = (q * p * q.conjugate()).v();
+            assert_vec_eq(q * v, expected, 1e-3);
+            assert_vec_eq(v * q, expected, 1e-3);
+            assert_vec_eq(q.rotate_vec3(v), expected, 1e-3);
+            assert_vec_eq(q.inverse() * (q * v), v, 1e-3);
+        }
+        let q = Quaternion::from_angle_around_axis(Vec3::Z, Rad(core::f32::consts::FRAC_PI_2));
+        assert_vec_eq(q * Vec3::X, Vec3::Y, 1e-3);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn rotate_non_unit() {
+        let _ = (Quaternion::IDENTITY * 2.0) * Vec3::X;
+    }
+
+    #[test]
+    fn euler_compatibility() {
+        let mut rng = StdRng::seed_from_u64(9);
+        for _ in 0..1000 {
+            let [x, y, z] = [(); 3].map(|()| rng.gen_range(-3.0..3.0));
+            let q = Quaternion::euler(Rad(x), Rad(y), Rad(z));
+            assert_same_rotation(
+                Quaternion::from_euler(EulerOrder::XYZ, Rad(x), Rad(y), Rad(z)),
+                q,
+            );
+            assert_same_rotation(
+                Quaternion::from_euler_intrinsic(EulerOrder::ZYX, Rad(z), Rad(y), Rad(x)),
+                q,
+            );
+        }
+    }
+
+    #[test]
+    fn euler_round_trip() {
+        let mut rng = StdRng::seed_from_u64(10);
+        for order in EulerOrder::ALL {
+            for _ in 0..500 {
+                let q = random_rotation(&mut rng);
+
+                let [a, b, c] = q.to_euler_angles(order);
+                assert_same_rotation(Quaternion::from_euler(order, a, b, c), q);
+
+                let [a, b, c] = q.to_euler_angles_intrinsic(order);
+                assert_same_rotation(Quaternion::from_euler_intrinsic(order, a, b, c), q);
+            }
+        }
+    }
+
+    #[test]
+    fn euler_gimbal_lock() {
+        use core::f32::consts::{FRAC_PI_2, PI};
+        for order in EulerOrder::ALL {
+            let locked: &[f32] = if order.is_proper() {
+                &[0.0, PI, -PI]
+            } else {
+                &[FRAC_PI_2, -FRAC_PI_2]
+            };
+            for &b in locked {
+                for (a, c) in [(0.3, 0.0), (0.3, 0.5), (-1.2, 2.0)] {
+                    let q = Quaternion::from_euler(order, Rad(a), Rad(b), Rad(c));
+                    let angles = q.to_euler_angles(order);
+                    assert!(angles.iter().all(|angle| angle.0.is_finite()));
+                    assert_eq!(angles[2], Rad(0.0));
+                    let [ra, rb, rc] = angles;
+                    assert_same_rotation(Quaternion::from_euler(order, ra, rb, rc), q);
+
+                    let q = Quaternion::from_euler_intrinsic(order, Rad(a), Rad(b), Rad(c));
+                    let angles = q.to_euler_angles_intrinsic(order);
+                    assert!(angles.iter().all(|angle| angle.0.is_finite()));
+                    assert_eq!(angles[2], Rad(0.0));
+                    let [ra, rb, rc] = angles;
+                    assert_same_rotation(Quaternion::from_euler_intrinsic(order, ra, rb, rc), q);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn swing_twist() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..1000 {
+            let q = random_rotation(&mut rng);
+            let axis = random_rotation(&mut rng).v() * 2.0;
+            let (swing, twist) = q.swing_twist(axis);
+            assert_quat_eq(swing * twist, q);
+            assert!((swing.length() - 1.0).abs() < EPSILON);
+            assert!((twist.length() - 1.0).abs() < EPSILON);
+            // the twist rotates around the axis, the swing around a perpendicular one
+            assert_vec_eq(twist.v().cross(axis), Vec3::ZERO, 1e-3);
+            assert!(swing.v().dot(axis).abs() < 1e-4);
+            let angle = q.twist_angle(axis);
+            assert_same_rotation(Quaternion::from_angle_around_axis(axis, angle), twist);
+        }
+        let half_turn = Quaternion::from_angle_around_axis(Vec3::X, Rad(core::f32::consts::PI));
+        assert_eq!(
+            half_turn.swing_twist(Vec3::Y),
+            (half_turn, Quaternion::IDENTITY)
+        );
+    }
+
+    #[test]
+    fn swing_twist_limits() {
+        let swing = Quaternion::from_angle_around_axis(Vec3::X, Rad(1.0));
+        let twist = Quaternion::from_angle_around_axis(Vec3::Y, Rad(-2.0));
+        let q = swing * twist;
+
+        assert_quat_eq(
+            q.clamp_swing_twist(Vec3::Y, Rad(2.0), Rad(-3.0), Rad(3.0)),
+            q,
+        );
+        assert_quat_eq(
+            q.clamp_swing_twist(Vec3::Y, Rad(0.5), Rad(-1.0), Rad(3.0)),
+            Quaternion::from_angle_around_axis(Vec3::X, Rad(0.5))
+                * Quaternion::from_angle_around_axis(Vec3::Y, Rad(-1.0)),
+        );
+        assert!((q.twist_angle(Vec3::Y).0 + 2.0).abs() < 1e-4);
+        assert_quat_eq(
+            swing.clamp_angle(Rad(0.25)),
+            Quaternion::from_angle_around_axis(Vec3::X, Rad(0.25)),
+        );
+        assert_quat_eq(
+            (-swing).clamp_angle(Rad(0.25)),
+            Quaternion::from_angle_around_axis(Vec3::X, Rad(0.25)),
+        );
+    }
+
+    #[test]
+    fn integration() {
+        let mut rng = StdRng::seed_from_u64(12);
+        for _ in 0..200 {
+            let q = random_rotation(&mut rng);
+            let omega = random_rotation(&mut rng).v() * 3.0;
+
+            let mut exact = q;
+            let mut first_order = q;
+            for _ in 0..100 {
+                exact = exact.integrate(omega, 0.01);
+                first_order = first_order.integrate_first_order(omega, 0.01);
+            }
+            let expected = Quaternion::from_scaled_axis(omega) * q;
+            assert_same_rotation(exact, expected);
+            assert!(first_order.angle_between(expected) < Rad(0.05));
+            assert!((first_order.length() - 1.0).abs() < EPSILON);
+
+            let dt = rng.gen_range(0.1..1.0);
+            let v = Quaternion::angular_velocity_between(q, q.integrate(omega, dt), dt);
+            assert_vec_eq(v, omega, 1e-3);
+        }
+        let q = Quaternion::from_angle_around_axis(Vec3::Z, Rad(0.5));
+        assert_eq!(q.integrate(Vec3::ZERO, 1.0), q);
+        assert_eq!(Quaternion::angular_velocity_between(q, q, 0.5), Vec3::ZERO);
+    }
+
+    #[test]
+    fn vector_parity() {
+        use crate::swizzle::*;
+
+        let mut q = Quaternion::new(4.0, 1.0, 2.0, 3.0);
+        assert_eq!(q.xyz(), q.v());
+        assert_eq!(q.wxyz(), Vec4::new(4.0, 1.0, 2.0, 3.0));
+        assert_eq!([q[0], q[1], q[2], q[3]], [1.0, 2.0, 3.0, 4.0]);
+        q[3] = 5.0;
+        q.set_x(-1.0);
+        assert_eq!(q, Quaternion::new(5.0, -1.0, 2.0, 3.0));
+
+        assert_eq!(q.to_xyzw(), [-1.0, 2.0, 3.0, 5.0]);
+        assert_eq!(q.to_wxyz(), [5.0, -1.0, 2.0, 3.0]);
+        assert_eq!(Quaternion::from_xyzw(q.to_xyzw()), q);
+        assert_eq!(Quaternion::from_wxyz((5.0, -1.0, 2.0, 3.0)), q);
+        assert_eq!(Quaternion::from_xyzw(Vec4::from(q)), q);
+        assert_eq!(Quaternion::from(Vec4::new(-1.0, 2.0, 3.0, 5.0)), q);
+        assert_eq!(Quaternion::from_bits(q.to_bits()), q);
+        assert_ne!((-q).to_bits(), q.to_bits());
+
+        assert_eq!(Quaternion::default(), Quaternion::IDENTITY);
+        assert_eq!(format!("{q}"), "5 - 1i + 2j + 3k");
+        assert_eq!(
+            format!("{:.1}", Quaternion::IDENTITY),
+            "1.0 + 0.0i + 0.0j + 0.0k"
+        );
+    }
+}
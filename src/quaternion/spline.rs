@@ -0,0 +1,137 @@
+use super::Quaternion;
+
+/// A smooth rotation curve through a sequence of keyframes using [`Quaternion::squad`].
+///
+/// The control points are calculated from the neighboring keyframes like a Catmull-Rom spline so
+/// the angular velocity is continuous at the keyframes. The keys can be stored in any type
+/// implementing `AsRef<[Quaternion]>`, for example an array, a slice or a `Vec`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuaternionSpline<K> {
+    keys: K,
+}
+
+impl<K: AsRef<[Quaternion]>> QuaternionSpline<K> {
+    /// Creates a spline through the unit quaternion keyframes.
+    /// Panics if there are no keyframes.
+    pub fn new(keys: K) -> Self {
+        assert!(
+            !keys.as_ref().is_empty(),
+            "a QuaternionSpline needs at least one keyframe"
+        );
+        Self { keys }
+    }
+
+    pub fn keys(&self) -> &[Quaternion] {
+        self.keys.as_ref()
+    }
+
+    pub fn into_keys(self) -> K {
+        self.keys
+    }
+
+    /// Samples the spline. The keyframe `i` is reached at `t = i` so the spline is defined in the
+    /// range `[0, keys.len() - 1]`. Values outside of the range are clamped.
+    #[must_use = "only samples the spline"]
+    pub fn sample(&self, t: f32) -> Quaternion {
+        let keys = self.keys();
+        if keys.len() == 1 {
+            return keys[0];
+        }
+        let last = keys.len() - 1;
+        let t = t.clamp(0.0, last as f32);
+        let i = (t as usize).min(last - 1);
+
+        // the neighbors are flipped into the same hemisphere so the curve takes the shortest path
+        let align = |q: Quaternion, to: Quaternion| if q.dot(to) < 0.0 { -q } else { q };
+        let q1 = keys[i];
+        let q0 = align(keys[i.saturating_sub(1)], q1);
+        let q2 = align(keys[i + 1], q1);
+        let q3 = align(keys[(i + 2).min(last)], q2);
+
+        let a = Quaternion::squad_control_point(q0, q1, q2);
+        let b = Quaternion::squad_control_point(q1, q2, q3);
+        q1.squad(q2, a, b, t - i as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rad, Vec3};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn random_path<const N: usize>(rng: &mut impl Rng) -> [Quaternion; N] {
+        let mut q = Quaternion::IDENTITY;
+        core::array::from_fn(|_| {
+            let axis = Vec3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            );
+            q = Quaternion::from_angle_around_axis(axis, Rad(rng.gen_range(0.2..1.5))) * q;
+            // the spline shouldn't care about which of the two equivalent quaternions is used
+            if rng.gen_bool(0.5) {
+                -q
+            } else {
+                q
+            }
+        })
+    }
+
+    /// angular velocity at t, approximated from the left or the right
+    fn angular_velocity(f: impl Fn(f32) -> Quaternion, t: f32, left: bool) -> Vec3 {
+        const H: f32 = 1e-3;
+        let (a, b) = if left { (t - H, t) } else { (t, t + H) };
+        (f(b) * f(a).conjugate()).to_scaled_axis() / H
+    }
+
+    #[test]
+    fn passes_through_keys() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let keys: [_; 6] = random_path(&mut rng);
+        let spline = QuaternionSpline::new(&keys[..]);
+        for (i, &key) in keys.iter().enumerate() {
+            let q = spline.sample(i as f32);
+            assert!(q.dot(key).abs() > 0.9999, "{q:?} != {key:?}");
+            assert!((q.length() - 1.0).abs() < 1e-4);
+        }
+        assert_eq!(spline.sample(-1.0), spline.sample(0.0));
+        assert_eq!(spline.sample(10.0), spline.sample(5.0));
+        assert_eq!(QuaternionSpline::new([keys[2]]).sample(0.5), keys[2]);
+    }
+
+    #[test]
+    fn continuous_angular_velocity() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..50 {
+            let keys: [_; 5] = random_path(&mut rng);
+            let spline = QuaternionSpline::new(keys);
+            for i in 1..keys.len() - 1 {
+                let t = i as f32;
+                let left = angular_velocity(|t| spline.sample(t), t, true);
+                let right = angular_velocity(|t| spline.sample(t), t, false);
+                assert!(
+                    (left - right).magnitude() < 0.02,
+                    "angular velocity jumps from {left} to {right} at keyframe {i}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn slerp_is_discontinuous() {
+        // sanity check that the continuity test above can detect jumps in angular velocity
+        let mut rng = StdRng::seed_from_u64(2);
+        let keys: [_; 3] = random_path(&mut rng);
+        let piecewise = |t: f32| {
+            if t < 1.0 {
+                keys[0].slerp(keys[1], t)
+            } else {
+                keys[1].slerp(keys[2], t - 1.0)
+            }
+        };
+        let left = angular_velocity(piecewise, 1.0, true);
+        let right = angular_velocity(piecewise, 1.0, false);
+        assert!((left - right).magnitude() > 0.1);
+    }
+}
@@ -0,0 +1,274 @@
+//! GLSL/HLSL-style functions that work on scalars and vectors alike, so code ported from shaders
+//! can keep its original shape.
+//!
+//! NOTE: Some functions intentionally follow GLSL semantics instead of the Rust ones. [`fract`]
+//! is `x - floor(x)` (and not `x - trunc(x)`) and [`modulo`] is GLSL's `mod`, which has the sign
+//! of `y` unlike Rust's `%`.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_traits::{Float, One, Zero};
+
+use crate::Vector;
+
+/// A float scalar or vector, like `genType` in GLSL. It is implemented for `f32`, `f64` and every
+/// [`Vector`] with float components.
+pub trait GenType:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + sealed::Components
+{
+}
+
+impl<T> GenType for T where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + sealed::Components
+{
+}
+
+mod sealed {
+    use super::*;
+
+    /// The per-component operations used by the functions. This is a separate trait so scalars
+    /// don't have to implement [`Vector`].
+    pub trait Components: Copy {
+        type Scalar: Float;
+
+        fn splat(s: Self::Scalar) -> Self;
+        fn map(self, f: impl FnMut(Self::Scalar) -> Self::Scalar) -> Self;
+        fn zip_map(
+            self,
+            other: Self,
+            f: impl FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar,
+        ) -> Self;
+        /// The dot product, which is the product for scalars.
+        fn dot(self, other: Self) -> Self::Scalar;
+    }
+
+    macro_rules! impl_scalar_components {
+        ($($s: ident)*) => {
+            $(
+                impl Components for $s {
+                    type Scalar = $s;
+
+                    #[inline]
+                    fn splat(s: $s) -> Self {
+                        s
+                    }
+                    #[inline]
+                    fn map(self, mut f: impl FnMut($s) -> $s) -> Self {
+                        f(self)
+                    }
+                    #[inline]
+                    fn zip_map(self, other: Self, mut f: impl FnMut($s, $s) -> $s) -> Self {
+                        f(self, other)
+                    }
+                    #[inline]
+                    fn dot(self, other: Self) -> $s {
+                        self * other
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_scalar_components!(f32 f64);
+
+    impl<V: Vector> Components for V
+    where
+        V::Scalar: Float,
+    {
+        type Scalar = V::Scalar;
+
+        #[inline]
+        fn splat(s: Self::Scalar) -> Self {
+            Vector::splat(s)
+        }
+        #[inline]
+        fn map(self, f: impl FnMut(Self::Scalar) -> Self::Scalar) -> Self {
+            Vector::map(self, f)
+        }
+        #[inline]
+        fn zip_map(
+            self,
+            other: Self,
+            f: impl FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar,
+        ) -> Self {
+            Vector::zip_map(self, other, f)
+        }
+        #[inline]
+        fn dot(self, other: Self) -> Self::Scalar {
+            Vector::dot(self, other)
+        }
+    }
+}
+
+/// Linearly interpolates between `x` and `y` per component, like [`lerp`](crate::lerp).
+#[inline]
+pub fn mix<T: GenType>(x: T, y: T, a: T) -> T {
+    crate::lerp(x, y, a)
+}
+
+/// [`mix`] with the same interpolation factor for every component, like GLSL's
+/// `mix(genType, genType, float)`.
+#[inline]
+pub fn mix_s<T: GenType>(x: T, y: T, a: T::Scalar) -> T {
+    mix(x, y, T::splat(a))
+}
+
+/// Returns 0 for components of `x` smaller than `edge` and 1 otherwise.
+#[inline]
+pub fn step<T: GenType>(edge: T, x: T) -> T {
+    edge.zip_map(x, |edge, x| {
+        if x < edge {
+            T::Scalar::zero()
+        } else {
+            T::Scalar::one()
+        }
+    })
+}
+
+/// [`step`] with the same edge for every component, like GLSL's `step(float, genType)`.
+#[inline]
+pub fn step_s<T: GenType>(edge: T::Scalar, x: T) -> T {
+    step(T::splat(edge), x)
+}
+
+/// Hermite interpolation between 0 and 1 when `x` is between `edge0` and `edge1`.
+#[inline]
+pub fn smoothstep<T: GenType>(edge0: T, edge1: T, x: T) -> T {
+    let t = saturate((x - edge0) / (edge1 - edge0));
+    let two = T::Scalar::one() + T::Scalar::one();
+    let three = two + T::Scalar::one();
+    t.map(|t| t * t * (three - two * t))
+}
+
+/// [`smoothstep`] with the same edges for every component, like GLSL's
+/// `smoothstep(float, float, genType)`.
+#[inline]
+pub fn smoothstep_s<T: GenType>(edge0: T::Scalar, edge1: T::Scalar, x: T) -> T {
+    smoothstep(T::splat(edge0), T::splat(edge1), x)
+}
+
+/// Clamps every component to the range [0, 1].
+#[inline]
+pub fn saturate<T: GenType>(x: T) -> T {
+    x.map(|x| x.max(T::Scalar::zero()).min(T::Scalar::one()))
+}
+
+/// The fractional part `x - floor(x)`, which is in the range [0, 1).
+#[inline]
+pub fn fract<T: GenType>(x: T) -> T {
+    x.map(|x| x - x.floor())
+}
+
+/// GLSL's `mod`: `x - y * floor(x / y)`. The result has the sign of `y`.
+#[inline]
+pub fn modulo<T: GenType>(x: T, y: T) -> T {
+    x.zip_map(y, |x, y| x - y * (x / y).floor())
+}
+
+/// Returns `n` if `nref` and `i` point in opposite directions and `-n` otherwise.
+#[inline]
+pub fn faceforward<T: GenType>(n: T, i: T, nref: T) -> T {
+    if nref.dot(i) < T::Scalar::zero() {
+        n
+    } else {
+        -n
+    }
+}
+
+/// `1 / sqrt(x)` per component.
+#[inline]
+pub fn inversesqrt<T: GenType>(x: T) -> T {
+    x.map(|x| x.sqrt().recip())
+}
+
+/// The length of a vector or the absolute value of a scalar.
+#[inline]
+pub fn length<T: GenType>(x: T) -> T::Scalar {
+    x.dot(x).sqrt()
+}
+
+/// Scales a vector to a length of one. Like in GLSL, zero vectors result in NaN.
+#[inline]
+pub fn normalize<T: GenType>(x: T) -> T {
+    let l = length(x);
+    x.map(|x| x / l)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn scalars_and_vectors() {
+        assert_eq!(mix(2.0, 4.0, 0.25), 2.5);
+        assert_eq!(
+            mix(Vec2::ZERO, Vec2::new(4.0, 8.0), Vec2::fill(0.5)),
+            Vec2::new(2.0, 4.0)
+        );
+        assert_eq!(step(0.5, 0.2_f32), 0.0);
+        assert_eq!(
+            step(Vec3::fill(0.5), Vec3::new(0.2, 0.5, 0.7)),
+            Vec3::new(0.0, 1.0, 1.0)
+        );
+        assert_eq!(smoothstep(0.0, 2.0, 1.0_f64), 0.5);
+        assert_eq!(
+            smoothstep(Vec2::ZERO, Vec2::ONE, Vec2::new(-1.0, 2.0)),
+            Vec2::new(0.0, 1.0)
+        );
+        assert_eq!(
+            mix_s(Vec3::ZERO, Vec3::new(2.0, 4.0, -8.0), 0.5),
+            Vec3::new(1.0, 2.0, -4.0)
+        );
+        assert_eq!(
+            step_s(0.5, Vec3::new(0.2, 0.5, 0.7)),
+            step(Vec3::fill(0.5), Vec3::new(0.2, 0.5, 0.7))
+        );
+        assert_eq!(
+            smoothstep_s(0.0, 2.0, Vec3::new(-1.0, 1.0, 3.0)),
+            Vec3::new(0.0, 0.5, 1.0)
+        );
+        assert_eq!(mix_s(2.0, 4.0, 0.25), mix(2.0, 4.0, 0.25));
+        assert_eq!(
+            saturate(Vec4::new(-1.0, 0.5, 1.5, 1.0)),
+            Vec4::new(0.0, 0.5, 1.0, 1.0)
+        );
+        assert_eq!(inversesqrt(Vec2::new(4.0, 16.0)), Vec2::new(0.5, 0.25));
+        assert_eq!(length(-3.0_f32), 3.0);
+        assert_eq!(length(Vec2::new(3.0, 4.0)), 5.0);
+        assert_eq!(normalize(Vec3::new(0.0, 0.0, -2.0)), -Vec3::Z);
+        assert_eq!(normalize(-2.0_f32), -1.0);
+        assert_eq!(
+            smoothstep_s(0.0, 2.0, crate::SVec::new([-1.0, 1.0, 3.0, 0.0, 2.0])),
+            crate::SVec::new([0.0, 0.5, 1.0, 0.0, 1.0])
+        );
+        assert_eq!(length(crate::SVec::new([1.0, 1.0, 1.0, 1.0])), 2.0);
+    }
+
+    #[test]
+    fn glsl_semantics() {
+        assert_eq!(fract(-0.25_f32), 0.75);
+        assert_eq!(fract(Vec2::new(1.5, -1.5)), Vec2::new(0.5, 0.5));
+        assert_eq!(modulo(-1.0_f32, 3.0), 2.0);
+        assert_eq!(
+            modulo(Vec2::new(5.0, -5.0), Vec2::fill(-3.0)),
+            Vec2::new(-1.0, -2.0)
+        );
+
+        let n = Vec3::Y;
+        assert_eq!(faceforward(n, Vec3::new(1.0, -1.0, 0.0), n), n);
+        assert_eq!(faceforward(n, Vec3::new(1.0, 1.0, 0.0), n), -n);
+    }
+}
@@ -0,0 +1,31 @@
+//! Helpers shared by the tests of different modules.
+
+use rand::Rng;
+
+use crate::{Quaternion, Rad, Vec3};
+
+/// A vector with every component in the range [-5, 5).
+pub fn random_vec3(rng: &mut impl Rng) -> Vec3 {
+    Vec3::new(
+        rng.gen_range(-5.0..5.0),
+        rng.gen_range(-5.0..5.0),
+        rng.gen_range(-5.0..5.0),
+    )
+}
+
+/// A unit quaternion rotating by up to half a turn around a random axis.
+pub fn random_rotation(rng: &mut impl Rng) -> Quaternion {
+    let axis = Vec3::new(
+        rng.gen_range(-1.0..1.0),
+        rng.gen_range(-1.0..1.0),
+        rng.gen_range(-1.0..1.0),
+    )
+    .normalized();
+    let angle = rng.gen_range(-core::f32::consts::PI..core::f32::consts::PI);
+    Quaternion::from_angle_around_axis(axis, Rad(angle))
+}
+
+#[track_caller]
+pub fn assert_vec_eq(a: Vec3, b: Vec3, epsilon: f32) {
+    assert!((a - b).magnitude() < epsilon, "{a} != {b}");
+}
@@ -0,0 +1,242 @@
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, Mul},
+    slice,
+};
+
+use num_traits::{One, Zero};
+
+use super::{PolyVec2, PolyVec3, PolyVec4};
+
+macro_rules! impl_iter {
+    ($($t: ident $n: literal: $($c: ident)*),*) => {
+        $(
+            impl<T> $t<T> {
+                /// Applies the function to every component, returning a vector of the results.
+                #[inline]
+                pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> $t<U> {
+                    $t { $($c: f(self.$c)),* }
+                }
+
+                /// Combines the components of two vectors using the function.
+                #[inline]
+                pub fn zip_map<U, V>(self, other: $t<U>, mut f: impl FnMut(T, U) -> V) -> $t<V> {
+                    $t { $($c: f(self.$c, other.$c)),* }
+                }
+
+                /// Combines the components in the order x, y, z, w into a single value.
+                #[inline]
+                pub fn fold<B>(self, init: B, mut f: impl FnMut(B, T) -> B) -> B {
+                    let acc = init;
+                    $(let acc = f(acc, self.$c);)*
+                    acc
+                }
+
+                #[inline]
+                pub fn as_array(&self) -> &[T; $n] {
+                    // SAFETY: the struct is `repr(C)` and only contains `$n` fields of type `T`,
+                    // so it has the same layout as `[T; $n]`
+                    unsafe { &*(self as *const Self as *const [T; $n]) }
+                }
+                #[inline]
+                pub fn as_mut_array(&mut self) -> &mut [T; $n] {
+                    // SAFETY: see `as_array`
+                    unsafe { &mut *(self as *mut Self as *mut [T; $n]) }
+                }
+                #[inline]
+                pub fn as_slice(&self) -> &[T] {
+                    self.as_array()
+                }
+                #[inline]
+                pub fn as_mut_slice(&mut self) -> &mut [T] {
+                    self.as_mut_array()
+                }
+
+                #[inline]
+                pub fn iter(&self) -> slice::Iter<'_, T> {
+                    self.as_slice().iter()
+                }
+                #[inline]
+                pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+                    self.as_mut_slice().iter_mut()
+                }
+
+                /// Returns the component at `index` or `None` if it is out of range, unlike
+                /// indexing which panics.
+                #[inline]
+                pub fn get(&self, index: usize) -> Option<&T> {
+                    self.as_slice().get(index)
+                }
+                #[inline]
+                pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+                    self.as_mut_slice().get_mut(index)
+                }
+
+                /// Creates a vector from the items of the iterator in the order x, y, z, w.
+                /// Returns `None` unless the iterator yields exactly one item per component.
+                pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Option<Self> {
+                    let mut iter = iter.into_iter();
+                    let v = $t { $($c: iter.next()?),* };
+                    match iter.next() {
+                        Some(_) => None,
+                        None => Some(v),
+                    }
+                }
+
+                /// Overwrites the components in the order x, y, z, w with the items of the
+                /// iterator and returns how many were written. Components without an item keep
+                /// their value and items beyond the last component are not consumed.
+                #[inline]
+                pub fn fill_from(&mut self, iter: impl IntoIterator<Item = T>) -> usize {
+                    let mut written = 0;
+                    for (c, item) in self.iter_mut().zip(iter) {
+                        *c = item;
+                        written += 1;
+                    }
+                    written
+                }
+            }
+
+            impl<T> IntoIterator for $t<T> {
+                type Item = T;
+                type IntoIter = core::array::IntoIter<T, $n>;
+
+                #[inline]
+                fn into_iter(self) -> Self::IntoIter {
+                    <[T; $n]>::from(self).into_iter()
+                }
+            }
+            impl<'a, T> IntoIterator for &'a $t<T> {
+                type Item = &'a T;
+                type IntoIter = slice::Iter<'a, T>;
+
+                #[inline]
+                fn into_iter(self) -> Self::IntoIter {
+                    self.iter()
+                }
+            }
+            impl<'a, T> IntoIterator for &'a mut $t<T> {
+                type Item = &'a mut T;
+                type IntoIter = slice::IterMut<'a, T>;
+
+                #[inline]
+                fn into_iter(self) -> Self::IntoIter {
+                    self.iter_mut()
+                }
+            }
+
+            impl<T: Zero + Add<Output = T>> Sum for $t<T> {
+                #[inline]
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Zero::zero(), |a, b| $t { $($c: a.$c + b.$c),* })
+                }
+            }
+            impl<'a, T: Zero + Add<Output = T> + Copy> Sum<&'a $t<T>> for $t<T> {
+                #[inline]
+                fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    iter.copied().sum()
+                }
+            }
+            impl<T: One + Mul<Output = T>> Product for $t<T> {
+                #[inline]
+                fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    let one = $t { $($c: T::one()),* };
+                    iter.fold(one, |a, b| $t { $($c: a.$c * b.$c),* })
+                }
+            }
+            impl<'a, T: One + Mul<Output = T> + Copy> Product<&'a $t<T>> for $t<T> {
+                #[inline]
+                fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    iter.copied().product()
+                }
+            }
+        )*
+    };
+}
+
+impl_iter!(PolyVec2 2: x y, PolyVec3 3: x y z, PolyVec4 4: x y z w);
+
+#[cfg(test)]
+mod tests {
+    use crate::{PolyVec4, Vec2, Vec2i, Vec3, Vec3i};
+
+    #[test]
+    fn iteration() {
+        let mut v = Vec3i::new(1, 2, 3);
+        assert_eq!(v.iter().copied().max(), Some(3));
+        for c in &mut v {
+            *c *= 10;
+        }
+        v.iter_mut().for_each(|c| *c += 1);
+        assert_eq!(v, Vec3i::new(11, 21, 31));
+        assert_eq!(
+            Vec3i::try_from_iter(v.into_iter().rev()),
+            Some(Vec3i::new(31, 21, 11))
+        );
+        assert_eq!((&v).into_iter().sum::<i32>(), 63);
+        assert_eq!(v.as_array(), &[11, 21, 31]);
+        assert_eq!(v.as_slice()[1..], [21, 31]);
+        v.as_mut_array()[2] = 0;
+        assert_eq!(v.get(2), Some(&0));
+        assert_eq!(v.get(3), None);
+        *v.get_mut(0).unwrap() = 5;
+        assert_eq!(v.get_mut(7), None);
+        assert_eq!(v, Vec3i::new(5, 21, 0));
+
+        // collections can be extended by a vector or a reference to one
+        let mut components = std::vec::Vec::new();
+        components.extend(Vec2::new(1.0, 2.0));
+        components.extend(&Vec2::new(3.0, 4.0));
+        assert_eq!(components, [1.0, 2.0, 3.0, 4.0]);
+
+        let mut v = Vec3i::new(1, 2, 3);
+        assert_eq!(v.fill_from([7, 8]), 2);
+        assert_eq!(v, Vec3i::new(7, 8, 3));
+        let mut items = [4, 5, 6, 7].into_iter();
+        assert_eq!(v.fill_from(&mut items), 3);
+        assert_eq!(v, Vec3i::new(4, 5, 6));
+        assert_eq!(items.next(), Some(7));
+        assert_eq!(v.fill_from(core::iter::empty()), 0);
+        assert_eq!(v, Vec3i::new(4, 5, 6));
+    }
+
+    #[test]
+    fn mapping() {
+        let v = Vec2i::new(3, -4);
+        assert_eq!(v.map(|c| c as f32 * 0.5), Vec2::new(1.5, -2.0));
+        assert_eq!(v.map(|c| c > 0), crate::PolyVec2::new(true, false));
+        assert_eq!(
+            v.zip_map(Vec2::new(0.5, 2.0), |a, b| a as f32 * b),
+            Vec2::new(1.5, -8.0)
+        );
+        assert_eq!(v.fold(10, |acc, c| acc * c), -120);
+        assert_eq!(
+            PolyVec4::new("a", "b", "c", "d").fold(0, |n, s| n + s.len()),
+            4
+        );
+    }
+
+    #[test]
+    fn collecting() {
+        let forces = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, -9.8, 0.0),
+            Vec3::new(0.5, 0.0, 2.0),
+        ];
+        assert_eq!(forces.iter().sum::<Vec3>(), Vec3::new(1.5, -9.8, 2.0));
+        assert_eq!(forces.into_iter().sum::<Vec3>(), Vec3::new(1.5, -9.8, 2.0));
+        assert_eq!([Vec3::ZERO; 0].iter().sum::<Vec3>(), Vec3::ZERO);
+        let scales = [Vec2i::new(2, 3), Vec2i::new(4, -1)];
+        assert_eq!(scales.iter().product::<Vec2i>(), Vec2i::new(8, -3));
+        assert_eq!(scales.into_iter().product::<Vec2i>(), Vec2i::new(8, -3));
+
+        assert_eq!(Vec3i::try_from_iter(1..4), Some(Vec3i::new(1, 2, 3)));
+        assert_eq!(Vec3i::try_from_iter(1..3), None);
+        assert_eq!(Vec3i::try_from_iter(1..5), None);
+        assert_eq!(Vec3i::try_from_iter(1..), None);
+        assert_eq!(
+            PolyVec4::try_from_iter("a b c d".split(' ')),
+            Some(PolyVec4::new("a", "b", "c", "d"))
+        );
+    }
+}
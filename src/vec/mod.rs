@@ -1,11 +1,17 @@
+mod iter;
 mod ops;
+mod svec;
+mod vector;
+
+pub use svec::SVec;
+pub use vector::Vector;
 
 use core::{
     default::Default,
     fmt,
-    ops::{Index, IndexMut},
+    ops::{Div, Index, IndexMut},
 };
-use num_traits::{ConstOne, ConstZero, One, Zero};
+use num_traits::{Bounded, ConstOne, ConstZero, Float, NumCast, One, ToPrimitive, Zero};
 
 pub trait X<T> {
     fn x(&self) -> &T;
@@ -29,6 +35,44 @@ pub trait Convert<T> {
     fn convert(self) -> T;
 }
 
+/// Used for TryFrom/TryInto conversions of Vector components, the fallible version of [`Convert`].
+pub trait TryConvert<T> {
+    type Error;
+
+    fn try_convert(self) -> Result<T, Self::Error>;
+}
+
+/// The error of [`TryConvert`] with the index of the first component that couldn't be converted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryConvertError<E> {
+    pub index: usize,
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for TryConvertError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to convert component {}: {}",
+            self.index, self.error
+        )
+    }
+}
+
+/// `as` conversions for vectors that clamp values to the range of the target type instead of
+/// wrapping. NaN becomes zero for integer targets and stays NaN for float targets.
+pub trait SaturatingCast<T> {
+    fn saturating_cast(self) -> T;
+}
+
+/// Conversions for vectors that round each component before saturating it to the target type
+/// like [`SaturatingCast`]. Integer components are left as they are.
+pub trait RoundToInt<T> {
+    fn round_to_int(self) -> T;
+    fn floor_to_int(self) -> T;
+    fn ceil_to_int(self) -> T;
+}
+
 // ---------- Struct definitions ----------
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -129,67 +173,117 @@ impl<T> PolyVec4<T> {
     }
 }
 
+// ---------- changing dimensions ----------
+
+impl<T> PolyVec2<T> {
+    /// Creates a [`PolyVec3`] by appending `z`.
+    #[inline]
+    pub fn extend(self, z: T) -> PolyVec3<T> {
+        PolyVec3::new(self.x, self.y, z)
+    }
+}
+
+impl<T> PolyVec3<T> {
+    /// Creates a [`PolyVec4`] by appending `w`.
+    #[inline]
+    pub fn extend(self, w: T) -> PolyVec4<T> {
+        PolyVec4::new(self.x, self.y, self.z, w)
+    }
+    /// Removes the z component.
+    #[inline]
+    pub fn truncate(self) -> PolyVec2<T> {
+        PolyVec2::new(self.x, self.y)
+    }
+}
+
+impl<T> PolyVec4<T> {
+    /// Creates a vector with the components of `xy` followed by the ones of `zw`.
+    #[inline]
+    pub fn from_parts(xy: PolyVec2<T>, zw: PolyVec2<T>) -> Self {
+        Self::new(xy.x, xy.y, zw.x, zw.y)
+    }
+    /// Removes the w component.
+    #[inline]
+    pub fn truncate(self) -> PolyVec3<T> {
+        PolyVec3::new(self.x, self.y, self.z)
+    }
+    /// Divides x, y and z by w, for example to turn homogeneous clip space coordinates into
+    /// normalized device coordinates.
+    #[inline]
+    pub fn xyz_div_w(self) -> PolyVec3<T>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        PolyVec3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
+}
+
 // ---------- convenience macros ----------
 
 #[macro_export]
 macro_rules! vec2 {
     ($v: expr) => {
-        $crate::vec::PolyVec2 { x: $v, y: $v }
+        $crate::PolyVec2 { x: $v, y: $v }
     };
     ($x: expr, ..) => {
-        $crate::vec::PolyVec2 {
+        $crate::PolyVec2 {
             x: $x,
             y: Default::default(),
         }
     };
     ($x: expr, $y: expr) => {
-        $crate::vec::PolyVec2 { x: $x, y: $y }
+        $crate::PolyVec2 { x: $x, y: $y }
     };
 }
 
 #[macro_export]
 macro_rules! vec3 {
     ($v: expr) => {
-        $crate::vec::PolyVec3 {
+        $crate::PolyVec3 {
             x: $v,
             y: $v,
             z: $v,
         }
     };
     ($x: expr, ..) => {
-        $crate::vec::PolyVec3 {
+        $crate::PolyVec3 {
             x: $x,
             y: Default::default(),
             z: Default::default(),
         }
     };
     ($x: expr, $y: expr, ..) => {
-        $crate::vec::PolyVec3 {
+        $crate::PolyVec3 {
             x: $x,
             y: $y,
             z: Default::default(),
         }
     };
     ($x: expr, $y: expr, $z: expr) => {
-        $crate::vec::PolyVec3 {
+        $crate::PolyVec3 {
             x: $x,
             y: $y,
             z: $z,
         }
     };
+    // vec3![xy, z]
+    ($a: expr, $b: expr) => {
+        <$crate::PolyVec3<_> as ::core::convert::From<_>>::from(($a, $b))
+    };
 }
 
 #[macro_export]
 macro_rules! vec4 {
     ($v: expr) => {
-        $crate::vec::PolyVec4 {
+        $crate::PolyVec4 {
             x: $v,
             y: $v,
             z: $v,
+            w: $v,
         }
     };
     ($x: expr, ..) => {
-        $crate::vec::PolyVec4 {
+        $crate::PolyVec4 {
             x: $x,
             y: Default::default(),
             z: Default::default(),
@@ -197,7 +291,7 @@ macro_rules! vec4 {
         }
     };
     ($x: expr, $y: expr, ..) => {
-        $crate::vec::PolyVec4 {
+        $crate::PolyVec4 {
             x: $x,
             y: $y,
             z: Default::default(),
@@ -205,7 +299,7 @@ macro_rules! vec4 {
         }
     };
     ($x: expr, $y: expr, $z: expr, ..) => {
-        $crate::vec::PolyVec4 {
+        $crate::PolyVec4 {
             x: $x,
             y: $y,
             z: $z,
@@ -213,13 +307,21 @@ macro_rules! vec4 {
         }
     };
     ($x: expr, $y: expr, $z: expr, $w: expr) => {
-        $crate::vec::PolyVec4 {
+        $crate::PolyVec4 {
             x: $x,
             y: $y,
             z: $z,
             w: $w,
         }
     };
+    // vec4![xyz, w] and vec4![xy, zw]
+    ($a: expr, $b: expr) => {
+        <$crate::PolyVec4<_> as ::core::convert::From<_>>::from(($a, $b))
+    };
+    // vec4![xy, z, w]
+    ($a: expr, $b: expr, $c: expr) => {
+        <$crate::PolyVec4<_> as ::core::convert::From<_>>::from(($a, $b, $c))
+    };
 }
 
 // ---------- indexing ----------
@@ -477,6 +579,30 @@ impl<T> From<(T, T, T, T)> for PolyVec4<T> {
         Self::new(t.0, t.1, t.2, t.3)
     }
 }
+impl<T> From<(PolyVec2<T>, T)> for PolyVec3<T> {
+    fn from((xy, z): (PolyVec2<T>, T)) -> Self {
+        xy.extend(z)
+    }
+}
+
+impl<T> From<(PolyVec3<T>, T)> for PolyVec4<T> {
+    fn from((xyz, w): (PolyVec3<T>, T)) -> Self {
+        xyz.extend(w)
+    }
+}
+
+impl<T> From<(PolyVec2<T>, T, T)> for PolyVec4<T> {
+    fn from((xy, z, w): (PolyVec2<T>, T, T)) -> Self {
+        Self::new(xy.x, xy.y, z, w)
+    }
+}
+
+impl<T> From<(PolyVec2<T>, PolyVec2<T>)> for PolyVec4<T> {
+    fn from((xy, zw): (PolyVec2<T>, PolyVec2<T>)) -> Self {
+        Self::from_parts(xy, zw)
+    }
+}
+
 impl<T> From<PolyVec4<T>> for (T, T, T, T) {
     fn from(v: PolyVec4<T>) -> (T, T, T, T) {
         (v.x, v.y, v.z, v.w)
@@ -556,6 +682,34 @@ where
     }
 }
 
+macro_rules! impl_try_convert {
+    ($($t: ident $($c: ident)*),*) => {
+        $(
+            impl<T, U> TryConvert<$t<U>> for $t<T>
+            where
+                T: TryInto<U>,
+            {
+                type Error = TryConvertError<T::Error>;
+
+                fn try_convert(self) -> Result<$t<U>, Self::Error> {
+                    let mut _index = 0;
+                    Ok($t {
+                        $($c: {
+                            let c = self.$c
+                                .try_into()
+                                .map_err(|error| TryConvertError { index: _index, error })?;
+                            _index += 1;
+                            c
+                        }),*
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_try_convert!(PolyVec2 x y, PolyVec3 x y z, PolyVec4 x y z w);
+
 // ---------- type conversions ----------
 
 /// Mirror trait for common `as` conversions for vectors not supported by From/Into implementations
@@ -620,153 +774,252 @@ macro_rules! impl_vec_as {
     };
 }
 
-impl_vec_as! {
-    // as f32
-    u8   => f32,
-    u16  => f32,
-    u32  => f32,
-    u64  => f32,
-    u128 => f32,
-    i8   => f32,
-    i16  => f32,
-    i32  => f32,
-    i64  => f32,
-    i128 => f32,
-    f64  => f32,
-    // as f64
-    u8   => f64,
-    u16  => f64,
-    u32  => f64,
-    u64  => f64,
-    u128 => f64,
-    i8   => f64,
-    i16  => f64,
-    i32  => f64,
-    i64  => f64,
-    i128 => f64,
-    f32  => f64,
-    // as unsigned int
-    u16  => u8,
-    u32  => u8,
-    u64  => u8,
-    u128 => u8,
-    i8   => u8,
-    i16  => u8,
-    i32  => u8,
-    i64  => u8,
-    i128 => u8,
-    f32  => u8,
-    f64  => u8,
-
-    u8   => u16,
-    u32  => u16,
-    u64  => u16,
-    u128 => u16,
-    i8   => u16,
-    i16  => u16,
-    i32  => u16,
-    i64  => u16,
-    i128 => u16,
-    f32  => u16,
-    f64  => u16,
-
-    u8   => u32,
-    u16  => u32,
-    u64  => u32,
-    u128 => u32,
-    i8   => u32,
-    i16  => u32,
-    i32  => u32,
-    i64  => u32,
-    i128 => u32,
-    f32  => u32,
-    f64  => u32,
-
-    u8   => u64,
-    u16  => u64,
-    u32  => u64,
-    u128 => u64,
-    i8   => u64,
-    i16  => u64,
-    i32  => u64,
-    i64  => u64,
-    i128 => u64,
-    f32  => u64,
-    f64  => u64,
-
-    u8   => u128,
-    u16  => u128,
-    u32  => u128,
-    u64  => u128,
-    i8   => u128,
-    i16  => u128,
-    i32  => u128,
-    i64  => u128,
-    i128 => u128,
-    f32  => u128,
-    f64  => u128,
-    // as signed int
-    u8   => i8,
-    u16  => i8,
-    u32  => i8,
-    u64  => i8,
-    u128 => i8,
-    i16  => i8,
-    i32  => i8,
-    i64  => i8,
-    i128 => i8,
-    f32  => i8,
-    f64  => i8,
-
-    u8   => i16,
-    u16  => i16,
-    u32  => i16,
-    u64  => i16,
-    u128 => i16,
-    i8   => i16,
-    i32  => i16,
-    i64  => i16,
-    i128 => i16,
-    f32  => i16,
-    f64  => i16,
-
-    u8   => i32,
-    u16  => i32,
-    u32  => i32,
-    u64  => i32,
-    u128 => i32,
-    i8   => i32,
-    i16  => i32,
-    i64  => i32,
-    i128 => i32,
-    f32  => i32,
-    f64  => i32,
-
-    u8   => i64,
-    u16  => i64,
-    u32  => i64,
-    u64  => i64,
-    u128 => i64,
-    i8   => i64,
-    i16  => i64,
-    i32  => i64,
-    i128 => i64,
-    f32  => i64,
-    f64  => i64,
-
-    u8   => i128,
-    u16  => i128,
-    u32  => i128,
-    u64  => i128,
-    u128 => i128,
-    i8   => i128,
-    i16  => i128,
-    i32  => i128,
-    i64  => i128,
-    f32  => i128,
-    f64  => i128
+/// Invokes the given macro with the list of all primitive `as` conversions supported by [`VecFrom`].
+/// This keeps vectors and matrices in sync when implementing the conversions.
+macro_rules! for_each_as_conversion {
+    ($m: ident) => {
+        $m! {
+            // as f32
+            u8   => f32,
+            u16  => f32,
+            u32  => f32,
+            u64  => f32,
+            u128 => f32,
+            i8   => f32,
+            i16  => f32,
+            i32  => f32,
+            i64  => f32,
+            i128 => f32,
+            f64  => f32,
+            // as f64
+            u8   => f64,
+            u16  => f64,
+            u32  => f64,
+            u64  => f64,
+            u128 => f64,
+            i8   => f64,
+            i16  => f64,
+            i32  => f64,
+            i64  => f64,
+            i128 => f64,
+            f32  => f64,
+            // as unsigned int
+            u16  => u8,
+            u32  => u8,
+            u64  => u8,
+            u128 => u8,
+            i8   => u8,
+            i16  => u8,
+            i32  => u8,
+            i64  => u8,
+            i128 => u8,
+            f32  => u8,
+            f64  => u8,
+
+            u8   => u16,
+            u32  => u16,
+            u64  => u16,
+            u128 => u16,
+            i8   => u16,
+            i16  => u16,
+            i32  => u16,
+            i64  => u16,
+            i128 => u16,
+            f32  => u16,
+            f64  => u16,
+
+            u8   => u32,
+            u16  => u32,
+            u64  => u32,
+            u128 => u32,
+            i8   => u32,
+            i16  => u32,
+            i32  => u32,
+            i64  => u32,
+            i128 => u32,
+            f32  => u32,
+            f64  => u32,
+
+            u8   => u64,
+            u16  => u64,
+            u32  => u64,
+            u128 => u64,
+            i8   => u64,
+            i16  => u64,
+            i32  => u64,
+            i64  => u64,
+            i128 => u64,
+            f32  => u64,
+            f64  => u64,
+
+            u8   => u128,
+            u16  => u128,
+            u32  => u128,
+            u64  => u128,
+            i8   => u128,
+            i16  => u128,
+            i32  => u128,
+            i64  => u128,
+            i128 => u128,
+            f32  => u128,
+            f64  => u128,
+            // as signed int
+            u8   => i8,
+            u16  => i8,
+            u32  => i8,
+            u64  => i8,
+            u128 => i8,
+            i16  => i8,
+            i32  => i8,
+            i64  => i8,
+            i128 => i8,
+            f32  => i8,
+            f64  => i8,
+
+            u8   => i16,
+            u16  => i16,
+            u32  => i16,
+            u64  => i16,
+            u128 => i16,
+            i8   => i16,
+            i32  => i16,
+            i64  => i16,
+            i128 => i16,
+            f32  => i16,
+            f64  => i16,
+
+            u8   => i32,
+            u16  => i32,
+            u32  => i32,
+            u64  => i32,
+            u128 => i32,
+            i8   => i32,
+            i16  => i32,
+            i64  => i32,
+            i128 => i32,
+            f32  => i32,
+            f64  => i32,
+
+            u8   => i64,
+            u16  => i64,
+            u32  => i64,
+            u64  => i64,
+            u128 => i64,
+            i8   => i64,
+            i16  => i64,
+            i32  => i64,
+            i128 => i64,
+            f32  => i64,
+            f64  => i64,
+
+            u8   => i128,
+            u16  => i128,
+            u32  => i128,
+            u64  => i128,
+            u128 => i128,
+            i8   => i128,
+            i16  => i128,
+            i32  => i128,
+            i64  => i128,
+            f32  => i128,
+            f64  => i128
+        }
+    };
+}
+pub(crate) use for_each_as_conversion;
+
+for_each_as_conversion!(impl_vec_as);
+
+fn saturating_component<A, B>(a: A) -> B
+where
+    A: ToPrimitive + PartialOrd + Zero + Copy,
+    B: NumCast + Bounded + PartialOrd,
+{
+    match B::from(a) {
+        // for example `u128::MAX as f32` and infinity are out of range
+        Some(b) if b > B::max_value() => B::max_value(),
+        Some(b) if b < B::min_value() => B::min_value(),
+        Some(b) => b,
+        None if a < A::zero() => B::min_value(),
+        None if a > A::zero() => B::max_value(),
+        // NaN
+        None => B::from(0).unwrap(),
+    }
+}
+
+/// Rounding of components, which does nothing for integers.
+trait RoundComponent: Copy {
+    fn round(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
 }
 
+macro_rules! impl_round_component {
+    (int: $($i: ty)*; float: $($f: ty)*) => {
+        $(
+            impl RoundComponent for $i {
+                fn round(self) -> Self {
+                    self
+                }
+                fn floor(self) -> Self {
+                    self
+                }
+                fn ceil(self) -> Self {
+                    self
+                }
+            }
+        )*
+        $(
+            impl RoundComponent for $f {
+                fn round(self) -> Self {
+                    Float::round(self)
+                }
+                fn floor(self) -> Self {
+                    Float::floor(self)
+                }
+                fn ceil(self) -> Self {
+                    Float::ceil(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_round_component!(
+    int: u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize;
+    float: f32 f64
+);
+
+macro_rules! impl_saturating_cast {
+    ($($a: ty => $b: ty),*) => {
+        $(
+            impl_saturating_cast!(@vec $a => $b, PolyVec2 x y);
+            impl_saturating_cast!(@vec $a => $b, PolyVec3 x y z);
+            impl_saturating_cast!(@vec $a => $b, PolyVec4 x y z w);
+        )*
+    };
+    (@vec $a: ty => $b: ty, $t: ident $($c: ident)*) => {
+        impl SaturatingCast<$t<$b>> for $t<$a> {
+            fn saturating_cast(self) -> $t<$b> {
+                $t { $($c: saturating_component(self.$c)),* }
+            }
+        }
+        impl RoundToInt<$t<$b>> for $t<$a> {
+            fn round_to_int(self) -> $t<$b> {
+                $t { $($c: saturating_component(self.$c.round())),* }
+            }
+            fn floor_to_int(self) -> $t<$b> {
+                $t { $($c: saturating_component(self.$c.floor())),* }
+            }
+            fn ceil_to_int(self) -> $t<$b> {
+                $t { $($c: saturating_component(self.$c.ceil())),* }
+            }
+        }
+    };
+}
+
+for_each_as_conversion!(impl_saturating_cast);
+
 // ---------- binverse implementations ----------
 
 #[cfg(feature = "binverse")]
@@ -4,6 +4,7 @@ use core::ops::{
 };
 
 use super::{PolyVec2, PolyVec3, PolyVec4};
+use crate::Rad;
 
 // ---------- Basic math operations ----------
 
@@ -174,6 +175,41 @@ macro_rules! impl_ops {
         impl_ops4!{PolyVec4:
             $($op = $f; $assign_op = $assign_f),*
         }
+        impl_ref_ops!{PolyVec2 [x y]:
+            $($op = $f; $assign_op = $assign_f),*
+        }
+        impl_ref_ops!{PolyVec3 [x y z]:
+            $($op = $f; $assign_op = $assign_f),*
+        }
+        impl_ref_ops!{PolyVec4 [x y z w]:
+            $($op = $f; $assign_op = $assign_f),*
+        }
+    };
+}
+
+/// Operators on references (`&a + &b`, `a += &b`) so generic code doesn't need
+/// `Copy` or `Clone` bounds on the components.
+macro_rules! impl_ref_ops {
+    ($t: ident $cs: tt: $($op: ident = $f: ident; $assign_op: ident = $assign_f: ident),*) => {
+        $(impl_ref_ops!(@op $t $cs $op $f $assign_op $assign_f);)*
+    };
+    (@op $t: ident [$($c: ident)*] $op: ident $f: ident $assign_op: ident $assign_f: ident) => {
+        impl<'a, 'b, T> $op<&'b $t<T>> for &'a $t<T>
+        where &'a T: $op<&'b T> {
+            type Output = $t<<&'a T as $op<&'b T>>::Output>;
+
+            #[inline]
+            fn $f(self, b: &'b $t<T>) -> Self::Output {
+                $t { $($c: (&self.$c).$f(&b.$c)),* }
+            }
+        }
+        impl<'b, T> $assign_op<&'b $t<T>> for $t<T>
+        where T: $assign_op<&'b T> {
+            #[inline]
+            fn $assign_f(&mut self, b: &'b $t<T>) {
+                $(self.$c.$assign_f(&b.$c);)*
+            }
+        }
     };
 }
 
@@ -190,15 +226,57 @@ impl_ops! {
     Shr = shr; ShrAssign = shr_assign
 }
 
+/// Operators with the scalar on the left side like `2.0 * v`. These can't be implemented
+/// generically because of the orphan rule so they are implemented for every primitive type.
+macro_rules! impl_scalar_lhs_ops {
+    ($($s: ident)*) => {
+        $(
+            impl_scalar_lhs_ops!(@vec $s PolyVec2 [x y]);
+            impl_scalar_lhs_ops!(@vec $s PolyVec3 [x y z]);
+            impl_scalar_lhs_ops!(@vec $s PolyVec4 [x y z w]);
+        )*
+    };
+    (@vec $s: ident $t: ident $cs: tt) => {
+        impl_scalar_lhs_ops!(@op $s $t $cs Add add);
+        impl_scalar_lhs_ops!(@op $s $t $cs Sub sub);
+        impl_scalar_lhs_ops!(@op $s $t $cs Mul mul);
+        impl_scalar_lhs_ops!(@op $s $t $cs Div div);
+    };
+    (@op $s: ident $t: ident [$($c: ident)*] $op: ident $f: ident) => {
+        impl $op<$t<$s>> for $s {
+            type Output = $t<$s>;
+
+            #[inline]
+            fn $f(self, v: $t<$s>) -> $t<$s> {
+                $t { $($c: self.$f(v.$c)),* }
+            }
+        }
+        impl<'a> $op<&'a $t<$s>> for $s {
+            type Output = $t<$s>;
+
+            #[inline]
+            fn $f(self, v: &'a $t<$s>) -> $t<$s> {
+                $t { $($c: self.$f(v.$c)),* }
+            }
+        }
+    };
+}
+
+impl_scalar_lhs_ops!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
 // ---------- magnitude mathematics ----------
 
 impl<T> PolyVec2<T>
 where
     T: Add<T, Output = T> + Mul<T, Output = T> + Copy,
 {
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
     #[inline]
     pub fn square_magnitude(&self) -> T {
-        self.x * self.x + self.y * self.y
+        self.dot(*self)
     }
     #[inline]
     pub fn magnitude(&self) -> T
@@ -207,6 +285,8 @@ where
     {
         self.square_magnitude().sqrt()
     }
+    /// Returns the vector scaled to a length of one. Zero vectors stay zero instead of becoming
+    /// NaN, use `try_normalized` to handle them explicitly.
     #[inline]
     pub fn normalized(&self) -> Self
     where
@@ -232,9 +312,13 @@ impl<T> PolyVec3<T>
 where
     T: Add<T, Output = T> + Mul<T, Output = T> + Copy,
 {
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
     #[inline]
     pub fn square_magnitude(&self) -> T {
-        self.x * self.x + self.y * self.y + self.z * self.z
+        self.dot(*self)
     }
     #[inline]
     pub fn magnitude(&self) -> T
@@ -243,6 +327,8 @@ where
     {
         self.square_magnitude().sqrt()
     }
+    /// Returns the vector scaled to a length of one. Zero vectors stay zero instead of becoming
+    /// NaN, use `try_normalized` to handle them explicitly.
     #[inline]
     pub fn normalized(&self) -> Self
     where
@@ -268,9 +354,13 @@ impl<T> PolyVec4<T>
 where
     T: Add<T, Output = T> + Mul<T, Output = T> + Copy,
 {
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
     #[inline]
     pub fn square_magnitude(&self) -> T {
-        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+        self.dot(*self)
     }
     #[inline]
     pub fn magnitude(&self) -> T
@@ -279,6 +369,8 @@ where
     {
         self.square_magnitude().sqrt()
     }
+    /// Returns the vector scaled to a length of one. Zero vectors stay zero instead of becoming
+    /// NaN, use `try_normalized` to handle them explicitly.
     #[inline]
     pub fn normalized(&self) -> Self
     where
@@ -300,36 +392,464 @@ where
     }
 }
 
+// ---------- distances, projection and interpolation ----------
+
+macro_rules! float_algebra {
+    ($($t: ident)*) => {
+        $(
+            impl<T: num_traits::Float> $t<T> {
+                /// Returns the normalized vector or `None` if the vector has a length of zero or
+                /// isn't finite. Unlike [`Self::normalized`], this distinguishes zero vectors.
+                #[inline]
+                pub fn try_normalized(&self) -> Option<Self> {
+                    let m = self.magnitude();
+                    if m.is_zero() || !m.is_finite() {
+                        None
+                    } else {
+                        Some(*self / m)
+                    }
+                }
+                #[inline]
+                pub fn distance(self, other: Self) -> T {
+                    (self - other).magnitude()
+                }
+                #[inline]
+                pub fn distance_squared(self, other: Self) -> T {
+                    (self - other).square_magnitude()
+                }
+                /// Projects the vector onto `onto`. Returns a zero vector if `onto` is zero.
+                #[inline]
+                pub fn project_onto(self, onto: Self) -> Self {
+                    let d = onto.dot(onto);
+                    if d.is_zero() {
+                        Self::zero()
+                    } else {
+                        onto * (self.dot(onto) / d)
+                    }
+                }
+                /// Returns the part of the vector that is perpendicular to `from`.
+                #[inline]
+                pub fn reject_from(self, from: Self) -> Self {
+                    self - self.project_onto(from)
+                }
+                /// Reflects the vector off a surface with the given normal. The normal should be
+                /// normalized.
+                #[inline]
+                pub fn reflect(self, normal: Self) -> Self {
+                    let two = T::one() + T::one();
+                    self - normal * (two * self.dot(normal))
+                }
+                /// Refracts the normalized incident vector through a surface with the given normal
+                /// and ratio of refraction indices `eta`. Both vectors should be normalized.
+                /// Returns `None` on total internal reflection.
+                #[inline]
+                pub fn refract(self, normal: Self, eta: T) -> Option<Self> {
+                    let d = self.dot(normal);
+                    let k = T::one() - eta * eta * (T::one() - d * d);
+                    if k < T::zero() {
+                        None
+                    } else {
+                        Some(self * eta - normal * (eta * d + k.sqrt()))
+                    }
+                }
+                /// Linearly interpolates between the vectors, `t = 0` returns `self`.
+                #[inline]
+                pub fn lerp(self, other: Self, t: T) -> Self {
+                    self + (other - self) * t
+                }
+                /// Moves towards `target` by at most `max_distance` without overshooting.
+                #[inline]
+                pub fn move_towards(self, target: Self, max_distance: T) -> Self {
+                    let delta = target - self;
+                    let d = delta.magnitude();
+                    if d <= max_distance || d.is_zero() {
+                        target
+                    } else {
+                        self + delta * (max_distance / d)
+                    }
+                }
+                /// Clamps the length of the vector to the range `[min, max]` while keeping its
+                /// direction. Zero vectors stay zero.
+                #[inline]
+                pub fn clamp_length(self, min: T, max: T) -> Self {
+                    let m = self.magnitude();
+                    if m.is_zero() {
+                        self
+                    } else if m < min {
+                        self * (min / m)
+                    } else if m > max {
+                        self * (max / m)
+                    } else {
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+float_algebra!(PolyVec2 PolyVec3 PolyVec4);
+
 // ---------- Trigonometry/float operations ----------
 
-macro_rules! trig {
-    ($($f: ident)*) => {
+/// Implements float functions per component. Unary functions map each component, binary
+/// functions combine the components with the ones of `other` and predicates return a vector of
+/// `bool`s.
+macro_rules! float_fns {
+    (
+        unary: $($f: ident)*;
+        binary: $($bf: ident)*;
+        predicates: $($p: ident)*;
+    ) => {
         impl<T: num_traits::Float> PolyVec2<T> {
             $(
                 #[inline]
                 pub fn $f(&self) -> Self { Self { x: self.x.$f(), y: self.y.$f() } }
             )*
+            $(
+                #[inline]
+                pub fn $bf(&self, other: Self) -> Self { Self { x: self.x.$bf(other.x), y: self.y.$bf(other.y) } }
+            )*
+            $(
+                #[inline]
+                pub fn $p(&self) -> PolyVec2<bool> { PolyVec2 { x: self.x.$p(), y: self.y.$p() } }
+            )*
         }
         impl<T: num_traits::Float> PolyVec3<T> {
             $(
                 #[inline]
                 pub fn $f(&self) -> Self { Self { x: self.x.$f(), y: self.y.$f(), z: self.z.$f() } }
             )*
+            $(
+                #[inline]
+                pub fn $bf(&self, other: Self) -> Self { Self { x: self.x.$bf(other.x), y: self.y.$bf(other.y), z: self.z.$bf(other.z) } }
+            )*
+            $(
+                #[inline]
+                pub fn $p(&self) -> PolyVec3<bool> { PolyVec3 { x: self.x.$p(), y: self.y.$p(), z: self.z.$p() } }
+            )*
         }
         impl<T: num_traits::Float> PolyVec4<T> {
             $(
                 #[inline]
                 pub fn $f(&self) -> Self { Self { x: self.x.$f(), y: self.y.$f(), z: self.z.$f(), w: self.w.$f() } }
             )*
+            $(
+                #[inline]
+                pub fn $bf(&self, other: Self) -> Self { Self { x: self.x.$bf(other.x), y: self.y.$bf(other.y), z: self.z.$bf(other.z), w: self.w.$bf(other.w) } }
+            )*
+            $(
+                #[inline]
+                pub fn $p(&self) -> PolyVec4<bool> { PolyVec4 { x: self.x.$p(), y: self.y.$p(), z: self.z.$p(), w: self.w.$p() } }
+            )*
         }
     };
 }
 
-trig! {
-    sin asin sinh asinh
-    cos acos cosh acosh
-    tan atan tanh atanh
-    round floor ceil
+float_fns! {
+    unary:
+        sin asin sinh asinh
+        cos acos cosh acosh
+        tan atan tanh atanh
+        round floor ceil fract trunc
+        sqrt cbrt recip
+        exp exp2 ln log2 log10
+        to_degrees to_radians;
+    binary:
+        atan2 copysign hypot;
+    predicates:
+        is_nan is_finite is_infinite is_normal
+        is_sign_positive is_sign_negative;
+}
+
+/// Per-component functions that take additional arguments. `min`, `max`, `clamp`, `abs` and
+/// `signum` are also available for integer vectors.
+macro_rules! component_fns {
+    ($t: ident $($c: ident)*) => {
+        impl<T: num_traits::Float> $t<T> {
+            #[inline]
+            pub fn powf(&self, n: T) -> Self {
+                Self { $($c: self.$c.powf(n)),* }
+            }
+            #[inline]
+            pub fn powi(&self, n: i32) -> Self {
+                Self { $($c: self.$c.powi(n)),* }
+            }
+            #[inline]
+            pub fn log(&self, base: T) -> Self {
+                Self { $($c: self.$c.log(base)),* }
+            }
+            /// Calculates `self * a + b` per component with only one rounding error.
+            #[inline]
+            pub fn mul_add(&self, a: Self, b: Self) -> Self {
+                Self { $($c: self.$c.mul_add(a.$c, b.$c)),* }
+            }
+        }
+        // these take `self` by value so they are used instead of the lexicographic `Ord` methods
+        impl<T: PartialOrd + Copy> $t<T> {
+            /// The per-component minimum. If one of the components is NaN, the other one is
+            /// returned.
+            #[inline]
+            #[allow(clippy::eq_op)]
+            pub fn min(self, other: Self) -> Self {
+                Self {
+                    $($c: if other.$c < self.$c || self.$c != self.$c { other.$c } else { self.$c }),*
+                }
+            }
+            /// The per-component maximum. If one of the components is NaN, the other one is
+            /// returned.
+            #[inline]
+            #[allow(clippy::eq_op)]
+            pub fn max(self, other: Self) -> Self {
+                Self {
+                    $($c: if other.$c > self.$c || self.$c != self.$c { other.$c } else { self.$c }),*
+                }
+            }
+            /// Restricts each component to the range given by the components of `min` and `max`.
+            /// NaN components stay NaN.
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self {
+                    $($c: if self.$c < min.$c { min.$c } else if self.$c > max.$c { max.$c } else { self.$c }),*
+                }
+            }
+        }
+        impl<T: num_traits::Signed> $t<T> {
+            #[inline]
+            pub fn abs(&self) -> Self {
+                Self { $($c: self.$c.abs()),* }
+            }
+            /// Returns the sign of each component: `-1`, `0` or `1` for integers and `-1.0` or `1.0`
+            /// (or NaN) for floats.
+            #[inline]
+            pub fn signum(&self) -> Self {
+                Self { $($c: self.$c.signum()),* }
+            }
+        }
+    };
+}
+
+component_fns!(PolyVec2 x y);
+component_fns!(PolyVec3 x y z);
+component_fns!(PolyVec4 x y z w);
+
+// ---------- comparisons and reductions ----------
+
+/// Per-component comparisons returning boolean masks. Unlike the derived `PartialOrd`, which
+/// compares lexicographically, these compare each component separately.
+macro_rules! mask_fns {
+    ($t: ident $first: ident $($c: ident)*) => {
+        impl<T: PartialEq> $t<T> {
+            #[inline]
+            pub fn cmpeq(&self, other: Self) -> $t<bool> {
+                $t { $first: self.$first == other.$first, $($c: self.$c == other.$c),* }
+            }
+            #[inline]
+            pub fn cmpne(&self, other: Self) -> $t<bool> {
+                $t { $first: self.$first != other.$first, $($c: self.$c != other.$c),* }
+            }
+        }
+        impl<T: PartialOrd> $t<T> {
+            #[inline]
+            pub fn cmplt(&self, other: Self) -> $t<bool> {
+                $t { $first: self.$first < other.$first, $($c: self.$c < other.$c),* }
+            }
+            #[inline]
+            pub fn cmple(&self, other: Self) -> $t<bool> {
+                $t { $first: self.$first <= other.$first, $($c: self.$c <= other.$c),* }
+            }
+            #[inline]
+            pub fn cmpgt(&self, other: Self) -> $t<bool> {
+                $t { $first: self.$first > other.$first, $($c: self.$c > other.$c),* }
+            }
+            #[inline]
+            pub fn cmpge(&self, other: Self) -> $t<bool> {
+                $t { $first: self.$first >= other.$first, $($c: self.$c >= other.$c),* }
+            }
+        }
+        impl $t<bool> {
+            /// Returns true if any component is true.
+            #[inline]
+            pub fn any(&self) -> bool {
+                self.$first $(|| self.$c)*
+            }
+            /// Returns true if all components are true.
+            #[inline]
+            pub fn all(&self) -> bool {
+                self.$first $(&& self.$c)*
+            }
+            /// Returns true if no component is true.
+            #[inline]
+            pub fn none(&self) -> bool {
+                !self.any()
+            }
+        }
+        impl<T> $t<T> {
+            /// Takes the components of `if_true` where the mask is true and the ones of `if_false`
+            /// everywhere else.
+            #[inline]
+            pub fn select(mask: $t<bool>, if_true: Self, if_false: Self) -> Self {
+                Self {
+                    $first: if mask.$first { if_true.$first } else { if_false.$first },
+                    $($c: if mask.$c { if_true.$c } else { if_false.$c }),*
+                }
+            }
+        }
+        impl<T: PartialOrd + Copy> $t<T> {
+            /// The smallest component. NaN components are ignored unless all of them are NaN.
+            #[inline]
+            pub fn min_element(&self) -> T {
+                self.min_element_index().1
+            }
+            /// The largest component. NaN components are ignored unless all of them are NaN.
+            #[inline]
+            pub fn max_element(&self) -> T {
+                self.max_element_index().1
+            }
+            /// The index and value of the smallest component. On ties the first one is returned.
+            #[inline]
+            #[allow(clippy::eq_op)]
+            pub fn min_element_index(&self) -> (usize, T) {
+                let mut best = (0, self.$first);
+                let mut _i = 0;
+                $(
+                    _i += 1;
+                    if self.$c < best.1 || best.1 != best.1 {
+                        best = (_i, self.$c);
+                    }
+                )*
+                best
+            }
+            /// The index and value of the largest component. On ties the first one is returned.
+            #[inline]
+            #[allow(clippy::eq_op)]
+            pub fn max_element_index(&self) -> (usize, T) {
+                let mut best = (0, self.$first);
+                let mut _i = 0;
+                $(
+                    _i += 1;
+                    if self.$c > best.1 || best.1 != best.1 {
+                        best = (_i, self.$c);
+                    }
+                )*
+                best
+            }
+        }
+        impl<T: Add<Output = T> + Copy> $t<T> {
+            /// The sum of all components.
+            #[inline]
+            pub fn sum(&self) -> T {
+                self.$first $(+ self.$c)*
+            }
+        }
+        impl<T: Mul<Output = T> + Copy> $t<T> {
+            /// The product of all components.
+            #[inline]
+            pub fn product(&self) -> T {
+                self.$first $(* self.$c)*
+            }
+        }
+    };
+}
+
+mask_fns!(PolyVec2 x y);
+mask_fns!(PolyVec3 x y z);
+mask_fns!(PolyVec4 x y z w);
+
+// ---------- integer arithmetic ----------
+
+/// Per-component versions of the integer methods of the standard library. `abs`, `signum`,
+/// `min`, `max` and `clamp` are implemented generically above.
+macro_rules! int_fns {
+    ($($s: ident => $u: ident)*) => {
+        $(
+            int_fns!(@vec $s $u PolyVec2 [x y]);
+            int_fns!(@vec $s $u PolyVec3 [x y z]);
+            int_fns!(@vec $s $u PolyVec4 [x y z w]);
+        )*
+    };
+    (@vec $s: ident $u: ident $t: ident [$($c: ident)*]) => {
+        impl $t<$s> {
+            /// Returns `None` if any of the components overflows.
+            #[inline]
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                Some(Self { $($c: self.$c.checked_add(other.$c)?),* })
+            }
+            /// Returns `None` if any of the components overflows.
+            #[inline]
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(Self { $($c: self.$c.checked_sub(other.$c)?),* })
+            }
+            /// Returns `None` if any of the components overflows.
+            #[inline]
+            pub fn checked_mul(self, other: Self) -> Option<Self> {
+                Some(Self { $($c: self.$c.checked_mul(other.$c)?),* })
+            }
+            /// Returns `None` if any of the components of `other` is zero or the division
+            /// overflows.
+            #[inline]
+            pub fn checked_div(self, other: Self) -> Option<Self> {
+                Some(Self { $($c: self.$c.checked_div(other.$c)?),* })
+            }
+            #[inline]
+            pub fn wrapping_add(self, other: Self) -> Self {
+                Self { $($c: self.$c.wrapping_add(other.$c)),* }
+            }
+            #[inline]
+            pub fn wrapping_sub(self, other: Self) -> Self {
+                Self { $($c: self.$c.wrapping_sub(other.$c)),* }
+            }
+            #[inline]
+            pub fn wrapping_mul(self, other: Self) -> Self {
+                Self { $($c: self.$c.wrapping_mul(other.$c)),* }
+            }
+            #[inline]
+            pub fn wrapping_div(self, other: Self) -> Self {
+                Self { $($c: self.$c.wrapping_div(other.$c)),* }
+            }
+            #[inline]
+            pub fn saturating_add(self, other: Self) -> Self {
+                Self { $($c: self.$c.saturating_add(other.$c)),* }
+            }
+            #[inline]
+            pub fn saturating_sub(self, other: Self) -> Self {
+                Self { $($c: self.$c.saturating_sub(other.$c)),* }
+            }
+            #[inline]
+            pub fn saturating_mul(self, other: Self) -> Self {
+                Self { $($c: self.$c.saturating_mul(other.$c)),* }
+            }
+            #[inline]
+            pub fn saturating_div(self, other: Self) -> Self {
+                Self { $($c: self.$c.saturating_div(other.$c)),* }
+            }
+            /// Euclidean division which rounds towards negative infinity for positive divisors,
+            /// useful for finding the chunk containing a coordinate.
+            #[inline]
+            pub fn div_euclid(self, other: Self) -> Self {
+                Self { $($c: self.$c.div_euclid(other.$c)),* }
+            }
+            /// The remainder of [`div_euclid`](Self::div_euclid), which is never negative.
+            #[inline]
+            pub fn rem_euclid(self, other: Self) -> Self {
+                Self { $($c: self.$c.rem_euclid(other.$c)),* }
+            }
+            #[inline]
+            pub fn pow(self, exp: u32) -> Self {
+                Self { $($c: self.$c.pow(exp)),* }
+            }
+            /// The per-component absolute difference, which can't overflow.
+            #[inline]
+            pub fn abs_diff(self, other: Self) -> $t<$u> {
+                $t { $($c: self.$c.abs_diff(other.$c)),* }
+            }
+        }
+    };
+}
+
+int_fns! {
+    u8 => u8 u16 => u16 u32 => u32 u64 => u64 u128 => u128 usize => usize
+    i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128 isize => usize
 }
 
 // ---------- angle mathematics ----------
@@ -342,15 +862,15 @@ where
         + num_traits::float::Float
         + Copy,
 {
-    /// returns the angle between two vectors in radians
+    /// returns the angle between two vectors
     #[inline]
-    pub fn angle(&self, other: &Self) -> T {
-        ((self.x * other.x + self.y * other.y) / (self.magnitude() * other.magnitude())).acos()
+    pub fn angle(&self, other: &Self) -> Rad<T> {
+        Rad((self.dot(*other) / (self.magnitude() * other.magnitude())).acos())
     }
-    /// returns the angle between two vectors in degrees and assumes that both vectors have a length of 1 to simplify the calculation
+    /// returns the angle between two vectors and assumes that both vectors have a length of 1 to simplify the calculation
     #[inline]
-    pub fn angle_normalized(&self, other: &Self) -> T {
-        (self.x * other.x + self.y * other.y).acos()
+    pub fn angle_normalized(&self, other: &Self) -> Rad<T> {
+        Rad(self.dot(*other).acos())
     }
 }
 
@@ -358,17 +878,15 @@ impl<T> PolyVec3<T>
 where
     T: Add<T, Output = T> + Mul<T, Output = T> + Div<T, Output = T> + num_traits::Float + Copy,
 {
-    /// returns the angle between two vectors in radians
+    /// returns the angle between two vectors
     #[inline]
-    pub fn angle(&self, other: &Self) -> T {
-        ((self.x * other.x + self.y * other.y + self.z * other.z)
-            / (self.magnitude() * other.magnitude()))
-        .acos()
+    pub fn angle(&self, other: &Self) -> Rad<T> {
+        Rad((self.dot(*other) / (self.magnitude() * other.magnitude())).acos())
     }
-    /// returns the angle between two vectors in degrees and assumes that both vectors have a length of 1 to simplify the calculation
+    /// returns the angle between two vectors and assumes that both vectors have a length of 1 to simplify the calculation
     #[inline]
-    pub fn angle_normalized(&self, other: &Self) -> T {
-        (self.x * other.x + self.y * other.y + self.z * other.z).acos()
+    pub fn angle_normalized(&self, other: &Self) -> Rad<T> {
+        Rad(self.dot(*other).acos())
     }
 }
 
@@ -376,17 +894,80 @@ impl<T> PolyVec4<T>
 where
     T: Add<T, Output = T> + Mul<T, Output = T> + Div<T, Output = T> + num_traits::Float + Copy,
 {
-    /// returns the angle between two vectors in radians
+    /// returns the angle between two vectors
+    #[inline]
+    pub fn angle(&self, other: &Self) -> Rad<T> {
+        Rad((self.dot(*other) / (self.magnitude() * other.magnitude())).acos())
+    }
+    /// returns the angle between two vectors and assumes that both vectors have a length of 1 to simplify the calculation
+    #[inline]
+    pub fn angle_normalized(&self, other: &Self) -> Rad<T> {
+        Rad(self.dot(*other).acos())
+    }
+}
+
+// ---------- 2D operations ----------
+
+impl<T> PolyVec2<T>
+where
+    T: Sub<T, Output = T> + Mul<T, Output = T> + Neg<Output = T> + Copy,
+{
+    /// Returns the vector rotated by 90 degrees counter-clockwise.
+    #[inline]
+    pub fn perp(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+    /// The 2D cross product, which is the z component of the 3D cross product. It is positive if
+    /// `other` is counter-clockwise from `self`.
+    #[inline]
+    pub fn perp_dot(&self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T: num_traits::Float> PolyVec2<T> {
+    /// Creates a unit vector pointing in the direction of the angle, measured counter-clockwise
+    /// from the x axis.
+    #[inline]
+    pub fn from_angle(angle: impl Into<Rad<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::new(cos, sin)
+    }
+    /// The angle of the vector in the range [-pi, pi], measured counter-clockwise from the x axis.
+    #[inline]
+    pub fn to_angle(&self) -> Rad<T> {
+        Rad::atan2(self.y, self.x)
+    }
+    /// Rotates the vector counter-clockwise by the angle.
+    #[inline]
+    pub fn rotate(&self, angle: impl Into<Rad<T>>) -> Self {
+        self.rotate_by(Self::from_angle(angle))
+    }
+    /// Rotates the vector by a rotation stored as a unit complex number, for example created by
+    /// [`from_angle`](Self::from_angle). This is cheaper than [`rotate`](Self::rotate) when the
+    /// same rotation is applied to many vectors.
+    #[inline]
+    pub fn rotate_by(&self, rotation: Self) -> Self {
+        Self::new(
+            self.x * rotation.x - self.y * rotation.y,
+            self.x * rotation.y + self.y * rotation.x,
+        )
+    }
+    /// The angle in the range [-pi, pi] that `self` has to be rotated counter-clockwise by to
+    /// point in the direction of `other`.
+    #[inline]
+    pub fn signed_angle_to(&self, other: Self) -> Rad<T> {
+        Rad::atan2(self.perp_dot(other), self.dot(other))
+    }
+    /// Creates a vector from polar coordinates.
     #[inline]
-    pub fn angle(&self, other: &Self) -> T {
-        ((self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w)
-            / (self.magnitude() * other.magnitude()))
-        .acos()
+    pub fn from_polar(radius: T, angle: impl Into<Rad<T>>) -> Self {
+        Self::from_angle(angle) * radius
     }
-    /// returns the angle between two vectors in degrees and assumes that both vectors have a length of 1 to simplify the calculation
+    /// Returns the polar coordinates `(radius, angle)` of the vector.
     #[inline]
-    pub fn angle_normalized(&self, other: &Self) -> T {
-        (self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w).acos()
+    pub fn to_polar(&self) -> (T, Rad<T>) {
+        (self.magnitude(), self.to_angle())
     }
 }
 
@@ -452,3 +1033,74 @@ where
         }
     }
 }
+
+impl<T> PolyVec3<T>
+where
+    T: Add<T, Output = T> + Mul<T, Output = T> + Sub<T, Output = T> + Copy,
+{
+    /// The scalar triple product `self · (b × c)`, which is the signed volume of the
+    /// parallelepiped spanned by the three vectors.
+    #[inline]
+    pub fn scalar_triple(self, b: Self, c: Self) -> T {
+        self.dot(b.cross(c))
+    }
+    /// The vector triple product `self × (b × c)`.
+    #[inline]
+    pub fn vector_triple(self, b: Self, c: Self) -> Self {
+        self.cross(b.cross(c))
+    }
+}
+
+impl<T: num_traits::Float> PolyVec3<T> {
+    /// Returns some vector that is orthogonal to `self`. It isn't normalized and only zero if
+    /// `self` is zero.
+    #[inline]
+    pub fn any_orthogonal(&self) -> Self {
+        if self.x.abs() > self.y.abs() {
+            Self::new(-self.z, T::zero(), self.x)
+        } else {
+            Self::new(T::zero(), self.z, -self.y)
+        }
+    }
+    /// Returns two unit vectors that form an orthonormal basis together with `self`, which has
+    /// to be normalized. `(b1, b2, self)` is right-handed.
+    ///
+    /// Uses the branchless method from Duff et al., "Building an Orthonormal Basis, Revisited".
+    #[inline]
+    pub fn orthonormal_basis(&self) -> (Self, Self) {
+        let sign = T::one().copysign(self.z);
+        let a = -(sign + self.z).recip();
+        let b = self.x * self.y * a;
+        (
+            Self::new(
+                T::one() + sign * self.x * self.x * a,
+                sign * b,
+                -sign * self.x,
+            ),
+            Self::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+    /// Rotates the vector counter-clockwise around the axis by the angle using the Rodrigues
+    /// formula. The axis doesn't have to be normalized.
+    #[inline]
+    pub fn rotate_around(&self, axis: Self, angle: impl Into<Rad<T>>) -> Self {
+        let k = axis.normalized();
+        let (sin, cos) = angle.into().sin_cos();
+        *self * cos + k.cross(*self) * sin + k * (k.dot(*self) * (T::one() - cos))
+    }
+    /// The angle in the range [-pi, pi] from `self` to `other`. Its magnitude is the
+    /// unsigned angle between the vectors and only its sign depends on the axis: it is positive
+    /// if the rotation is counter-clockwise around the axis, which doesn't have to be normalized
+    /// or perpendicular to the vectors. If the axis lies in the plane of the vectors, the angle
+    /// is positive.
+    #[inline]
+    pub fn signed_angle(&self, other: Self, axis: Self) -> Rad<T> {
+        let cross = self.cross(other);
+        let angle = Rad::atan2(cross.magnitude(), self.dot(other));
+        if cross.dot(axis) < T::zero() {
+            -angle
+        } else {
+            angle
+        }
+    }
+}
@@ -0,0 +1,437 @@
+use core::{
+    fmt,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
+};
+
+use num_traits::Zero;
+
+use super::{PolyVec2, PolyVec3, PolyVec4};
+
+/// A vector with an arbitrary number of components for cases where [`PolyVec2`], [`PolyVec3`]
+/// and [`PolyVec4`] aren't enough, for example state vectors. It can be multiplied with a
+/// [`Mat`](crate::Mat) of a matching size.
+///
+/// With the `serde` feature, it is serialized like an array of its components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SVec<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> SVec<T, N> {
+    #[inline]
+    pub const fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+
+    #[inline]
+    pub fn fill(val: T) -> Self
+    where
+        T: Copy,
+    {
+        Self([val; N])
+    }
+
+    /// Applies the function to every component, returning a vector of the results.
+    #[inline]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> SVec<U, N> {
+        SVec(self.0.map(f))
+    }
+
+    /// Combines the components of two vectors using the function.
+    #[inline]
+    pub fn zip_map<U, V>(self, other: SVec<U, N>, mut f: impl FnMut(T, U) -> V) -> SVec<V, N> {
+        let mut a = self.0.into_iter();
+        let mut b = other.0.into_iter();
+        SVec(core::array::from_fn(|_| {
+            f(a.next().unwrap(), b.next().unwrap())
+        }))
+    }
+}
+
+impl<T: Zero + Copy, const N: usize> SVec<T, N> {
+    #[inline]
+    pub fn zero() -> Self {
+        Self([T::zero(); N])
+    }
+}
+
+impl<T: Default, const N: usize> Default for SVec<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| T::default()))
+    }
+}
+
+// ---------- magnitude mathematics ----------
+
+impl<T, const N: usize> SVec<T, N>
+where
+    T: Zero + Add<T, Output = T> + Mul<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.0
+            .iter()
+            .zip(other.0)
+            .fold(T::zero(), |sum, (&a, b)| sum + a * b)
+    }
+    #[inline]
+    pub fn square_magnitude(&self) -> T {
+        self.dot(*self)
+    }
+    #[inline]
+    pub fn magnitude(&self) -> T
+    where
+        T: num_traits::Float,
+    {
+        self.square_magnitude().sqrt()
+    }
+    /// Returns the vector scaled to a length of one. Zero vectors stay zero instead of becoming
+    /// NaN.
+    #[inline]
+    pub fn normalized(&self) -> Self
+    where
+        T: num_traits::Float,
+    {
+        let m = self.magnitude();
+        if m.is_zero() {
+            Self::zero()
+        } else {
+            *self / m
+        }
+    }
+    #[inline]
+    pub fn normalize(&mut self)
+    where
+        T: num_traits::Float,
+    {
+        *self = self.normalized();
+    }
+}
+
+// ---------- operators ----------
+
+macro_rules! impl_svec_ops {
+    ($($op: ident = $f: ident; $assign_op: ident = $assign_f: ident),*) => {
+        $(
+            impl<T: $op<T>, const N: usize> $op<SVec<T, N>> for SVec<T, N> {
+                type Output = SVec<<T as $op>::Output, N>;
+
+                #[inline]
+                fn $f(self, b: SVec<T, N>) -> Self::Output {
+                    self.zip_map(b, <T as $op>::$f)
+                }
+            }
+            impl<T: $op<T> + Clone, const N: usize> $op<T> for SVec<T, N> {
+                type Output = SVec<<T as $op>::Output, N>;
+
+                #[inline]
+                fn $f(self, b: T) -> Self::Output {
+                    self.map(|a| a.$f(b.clone()))
+                }
+            }
+            impl<'a, 'b, T, const N: usize> $op<&'b SVec<T, N>> for &'a SVec<T, N>
+            where &'a T: $op<&'b T> {
+                type Output = SVec<<&'a T as $op<&'b T>>::Output, N>;
+
+                #[inline]
+                fn $f(self, b: &'b SVec<T, N>) -> Self::Output {
+                    SVec(core::array::from_fn(|i| (&self.0[i]).$f(&b.0[i])))
+                }
+            }
+            impl<T: $assign_op<T>, const N: usize> $assign_op<SVec<T, N>> for SVec<T, N> {
+                #[inline]
+                fn $assign_f(&mut self, b: SVec<T, N>) {
+                    for (a, b) in self.0.iter_mut().zip(b.0) {
+                        a.$assign_f(b);
+                    }
+                }
+            }
+            impl<T: $assign_op<T> + Clone, const N: usize> $assign_op<T> for SVec<T, N> {
+                #[inline]
+                fn $assign_f(&mut self, b: T) {
+                    for a in &mut self.0 {
+                        a.$assign_f(b.clone());
+                    }
+                }
+            }
+            impl<'b, T: $assign_op<&'b T>, const N: usize> $assign_op<&'b SVec<T, N>> for SVec<T, N> {
+                #[inline]
+                fn $assign_f(&mut self, b: &'b SVec<T, N>) {
+                    for (a, b) in self.0.iter_mut().zip(&b.0) {
+                        a.$assign_f(b);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_svec_ops! {
+    Add = add; AddAssign = add_assign,
+    Sub = sub; SubAssign = sub_assign,
+    Mul = mul; MulAssign = mul_assign,
+    Div = div; DivAssign = div_assign,
+    Rem = rem; RemAssign = rem_assign,
+    BitAnd = bitand; BitAndAssign = bitand_assign,
+    BitOr = bitor; BitOrAssign = bitor_assign,
+    BitXor = bitxor; BitXorAssign = bitxor_assign,
+    Shl = shl; ShlAssign = shl_assign,
+    Shr = shr; ShrAssign = shr_assign
+}
+
+impl<T: Neg, const N: usize> Neg for SVec<T, N> {
+    type Output = SVec<<T as Neg>::Output, N>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.map(T::neg)
+    }
+}
+
+/// Operators with the scalar on the left side like `2.0 * v`, see the ones of `PolyVec2`.
+macro_rules! impl_scalar_lhs_ops {
+    ($($s: ident)*) => {
+        $(
+            impl_scalar_lhs_ops!(@op $s Add add);
+            impl_scalar_lhs_ops!(@op $s Sub sub);
+            impl_scalar_lhs_ops!(@op $s Mul mul);
+            impl_scalar_lhs_ops!(@op $s Div div);
+        )*
+    };
+    (@op $s: ident $op: ident $f: ident) => {
+        impl<const N: usize> $op<SVec<$s, N>> for $s {
+            type Output = SVec<$s, N>;
+
+            #[inline]
+            fn $f(self, v: SVec<$s, N>) -> SVec<$s, N> {
+                v.map(|c| self.$f(c))
+            }
+        }
+    };
+}
+
+impl_scalar_lhs_ops!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+// ---------- indexing ----------
+
+impl<T, const N: usize> Index<usize> for SVec<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, i: usize) -> &T {
+        &self.0[i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SVec<T, N> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.0[i]
+    }
+}
+
+// ---------- conversions ----------
+
+impl<T, const N: usize> From<[T; N]> for SVec<T, N> {
+    #[inline]
+    fn from(a: [T; N]) -> Self {
+        Self(a)
+    }
+}
+
+impl<T, const N: usize> From<SVec<T, N>> for [T; N] {
+    #[inline]
+    fn from(v: SVec<T, N>) -> Self {
+        v.0
+    }
+}
+
+macro_rules! impl_poly_vec_conversions {
+    ($($t: ident $n: literal: $($c: ident)*),*) => {
+        $(
+            impl<T> From<$t<T>> for SVec<T, $n> {
+                #[inline]
+                fn from(v: $t<T>) -> Self {
+                    Self([$(v.$c),*])
+                }
+            }
+            impl<T> From<SVec<T, $n>> for $t<T> {
+                #[inline]
+                fn from(SVec([$($c),*]): SVec<T, $n>) -> Self {
+                    Self { $($c),* }
+                }
+            }
+        )*
+    };
+}
+
+impl_poly_vec_conversions!(PolyVec2 2: x y, PolyVec3 3: x y z, PolyVec4 4: x y z w);
+
+// ---------- serde ----------
+
+// Serde only implements arrays up to a length of 32, so SVec is serialized like an array of any
+// length: a tuple of its components.
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for SVec<T, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for c in &self.0 {
+            tuple.serialize_element(c)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for SVec<T, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<T, const N: usize>(core::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::de::Visitor<'de> for Visitor<T, N> {
+            type Value = SVec<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an array of length {N}")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut error = None;
+                let components: [Option<T>; N] = core::array::from_fn(|i| {
+                    if error.is_some() {
+                        return None;
+                    }
+                    match seq.next_element() {
+                        Ok(Some(c)) => Some(c),
+                        Ok(None) => {
+                            error = Some(serde::de::Error::invalid_length(i, &self));
+                            None
+                        }
+                        Err(e) => {
+                            error = Some(e);
+                            None
+                        }
+                    }
+                });
+                match error {
+                    Some(e) => Err(e),
+                    None => Ok(SVec(components.map(|c| c.unwrap()))),
+                }
+            }
+        }
+
+        deserializer.deserialize_tuple(N, Visitor(core::marker::PhantomData))
+    }
+}
+
+// ---------- display ----------
+
+impl<T: fmt::Display, const N: usize> fmt::Display for SVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, c) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{c}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Mat, Vec3, Vec4};
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn operations() {
+        let a = SVec::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = SVec::fill(2.0);
+        assert_eq!(a + b, SVec::new([3.0, 4.0, 5.0, 6.0, 7.0, 8.0]));
+        assert_eq!(a * 2.0, 2.0 * a);
+        assert_eq!(&a - &b, a - b);
+        assert_eq!(-a, a * -1.0);
+        let mut c = a;
+        c /= b;
+        c += &b;
+        assert_eq!(c, SVec::new([2.5, 3.0, 3.5, 4.0, 4.5, 5.0]));
+        assert_eq!(SVec::new([1u8, 2, 4]) << 1, SVec::new([2, 4, 8]));
+
+        assert_eq!(a.dot(b), 42.0);
+        assert_eq!(SVec::new([2.0, 3.0, 6.0]).magnitude(), 7.0);
+        assert_eq!(
+            SVec::new([0.0, 0.0, 3.0]).normalized(),
+            SVec::new([0.0, 0.0, 1.0])
+        );
+        assert_eq!(SVec::<f32, 8>::zero().normalized(), SVec::zero());
+        assert_eq!(a[2], 3.0);
+        assert_eq!(format!("{}", SVec::new([1, 2, 3])), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn conversions() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        let s: SVec<f32, 3> = v.into();
+        assert_eq!(s, SVec::new([1.0, 2.0, 3.0]));
+        assert_eq!(Vec3::from(s), v);
+        assert_eq!(
+            Vec4::from(SVec::new([1.0, 2.0, 3.0, 4.0])),
+            Vec4::new(1.0, 2.0, 3.0, 4.0)
+        );
+        assert_eq!(
+            <[i32; 2]>::from(SVec::from(crate::Vec2i::new(5, 6))),
+            [5, 6]
+        );
+    }
+
+    #[test]
+    fn matrix_multiplication() {
+        let m = Mat::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(m * SVec::new([1.0, 0.0, -1.0]), SVec::new([-2.0, -2.0]));
+
+        let q =
+            crate::Quaternion::from_angle_around_axis(Vec3::new(1.0, 2.0, 3.0), crate::Rad(1.0));
+        let v = Vec3::new(-2.0, 0.5, 1.0);
+        let mut m = q.matrix();
+        m.translate(Vec3::new(1.0, 2.0, 3.0));
+        let transformed = Vec4::from(m * SVec::from(v.extend(1.0)));
+        assert!((transformed.truncate() - (q * v + Vec3::new(1.0, 2.0, 3.0))).magnitude() < 1e-5);
+        assert_eq!(transformed.w, 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize() {
+        use serde::de::{
+            value::{Error, SeqDeserializer},
+            Deserialize,
+        };
+        fn seq<const N: usize>(
+            items: [i32; N],
+        ) -> SeqDeserializer<core::array::IntoIter<i32, N>, Error> {
+            SeqDeserializer::new(items.into_iter())
+        }
+        assert_eq!(
+            SVec::<i32, 5>::deserialize(seq([1, 2, 3, 4, 5])),
+            Ok(SVec::new([1, 2, 3, 4, 5]))
+        );
+        assert!(SVec::<i32, 5>::deserialize(seq([1, 2, 3])).is_err());
+        assert!(SVec::<i32, 2>::deserialize(seq([1, 2, 3])).is_err());
+    }
+}
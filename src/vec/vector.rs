@@ -0,0 +1,248 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use num_traits::{Float, Num, Zero};
+
+use super::{PolyVec2, PolyVec3, PolyVec4, SVec};
+
+/// A trait implemented by [`PolyVec2`], [`PolyVec3`], [`PolyVec4`] and [`SVec`] to write code
+/// that works for any number of dimensions.
+///
+/// The vectors also implement the operators component-wise with other vectors of the same type.
+/// Use a bound like `V: Mul<V::Scalar, Output = V>` if scalar operators are needed.
+pub trait Vector:
+    Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    type Scalar: Num + Copy;
+    /// The components as an array, which is `[Self::Scalar; Self::DIM]`.
+    type Array: AsRef<[Self::Scalar]>
+        + AsMut<[Self::Scalar]>
+        + IntoIterator<Item = Self::Scalar>
+        + Into<Self>
+        + From<Self>;
+
+    /// The number of components.
+    const DIM: usize;
+
+    /// Creates a vector with all components set to `s`.
+    fn splat(s: Self::Scalar) -> Self;
+
+    /// Applies the function to every component.
+    fn map(self, f: impl FnMut(Self::Scalar) -> Self::Scalar) -> Self;
+
+    /// Combines the components of two vectors using the function.
+    fn zip_map(
+        self,
+        other: Self,
+        f: impl FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar,
+    ) -> Self;
+
+    #[inline]
+    fn from_array(a: Self::Array) -> Self {
+        a.into()
+    }
+
+    #[inline]
+    fn to_array(self) -> Self::Array {
+        self.into()
+    }
+
+    /// Returns an iterator over the components in the order x, y, z, w.
+    #[inline]
+    fn components(self) -> <Self::Array as IntoIterator>::IntoIter {
+        self.to_array().into_iter()
+    }
+
+    #[inline]
+    fn dot(self, other: Self) -> Self::Scalar {
+        (self * other)
+            .components()
+            .fold(Self::Scalar::zero(), |sum, c| sum + c)
+    }
+
+    #[inline]
+    fn square_magnitude(self) -> Self::Scalar {
+        self.dot(self)
+    }
+
+    #[inline]
+    fn magnitude(self) -> Self::Scalar
+    where
+        Self::Scalar: Float,
+    {
+        self.square_magnitude().sqrt()
+    }
+}
+
+macro_rules! impl_vector {
+    ($($t: ident $n: literal),*) => {
+        $(
+            impl<T: Num + Copy> Vector for $t<T> {
+                type Scalar = T;
+                type Array = [T; $n];
+
+                const DIM: usize = $n;
+
+                #[inline]
+                fn splat(s: T) -> Self {
+                    Self::fill(s)
+                }
+
+                #[inline]
+                fn map(self, f: impl FnMut(T) -> T) -> Self {
+                    $t::map(self, f)
+                }
+
+                #[inline]
+                fn zip_map(self, other: Self, f: impl FnMut(T, T) -> T) -> Self {
+                    $t::zip_map(self, other, f)
+                }
+
+                #[inline]
+                fn dot(self, other: Self) -> T {
+                    $t::dot(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_vector!(PolyVec2 2, PolyVec3 3, PolyVec4 4);
+
+impl<T: Num + Copy, const N: usize> Vector for SVec<T, N> {
+    type Scalar = T;
+    type Array = [T; N];
+
+    const DIM: usize = N;
+
+    #[inline]
+    fn splat(s: T) -> Self {
+        Self::fill(s)
+    }
+
+    #[inline]
+    fn map(self, f: impl FnMut(T) -> T) -> Self {
+        SVec::map(self, f)
+    }
+
+    #[inline]
+    fn zip_map(self, other: Self, f: impl FnMut(T, T) -> T) -> Self {
+        SVec::zip_map(self, other, f)
+    }
+
+    #[inline]
+    fn dot(self, other: Self) -> T {
+        SVec::dot(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3, Vec3i, Vec4};
+    use num_traits::One;
+
+    /// dimension-generic bounding box
+    fn bounds<V: Vector>(points: &[V]) -> (V, V)
+    where
+        V::Scalar: PartialOrd,
+    {
+        let min = |a: V::Scalar, b: V::Scalar| if b < a { b } else { a };
+        let max = |a: V::Scalar, b: V::Scalar| if b > a { b } else { a };
+        points[1..]
+            .iter()
+            .fold((points[0], points[0]), |(lo, hi), &p| {
+                (lo.zip_map(p, min), hi.zip_map(p, max))
+            })
+    }
+
+    /// one k-means iteration that returns the new centers
+    fn k_means_step<V: Vector, const K: usize>(points: &[V], centers: [V; K]) -> [V; K]
+    where
+        V::Scalar: Float,
+    {
+        let mut sums = [V::splat(V::Scalar::zero()); K];
+        let mut counts = [V::Scalar::zero(); K];
+        for &p in points {
+            let (closest, _) = centers
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| (i, (p - c).square_magnitude()))
+                .fold(
+                    (0, V::Scalar::infinity()),
+                    |a, b| if b.1 < a.1 { b } else { a },
+                );
+            sums[closest] = sums[closest] + p;
+            counts[closest] = counts[closest] + V::Scalar::one();
+        }
+        core::array::from_fn(|i| sums[i] / V::splat(counts[i]))
+    }
+
+    #[test]
+    fn generic_algorithms() {
+        let points2 = [
+            Vec2::new(0.0, 1.0),
+            Vec2::new(-2.0, 4.0),
+            Vec2::new(3.0, -1.0),
+        ];
+        assert_eq!(
+            bounds(&points2),
+            (Vec2::new(-2.0, -1.0), Vec2::new(3.0, 4.0))
+        );
+        let points3 = [Vec3i::new(1, 2, 3), Vec3i::new(-1, 5, 0)];
+        assert_eq!(
+            bounds(&points3),
+            (Vec3i::new(-1, 2, 0), Vec3i::new(1, 5, 3))
+        );
+
+        let clusters = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.2, 0.1),
+            Vec3::new(10.0, 10.0, 10.0),
+            Vec3::new(10.0, 9.8, 10.2),
+        ];
+        let centers = k_means_step(&clusters, [Vec3::ZERO, Vec3::fill(5.1)]);
+        let expected = [Vec3::new(0.0, 0.1, 0.05), Vec3::new(10.0, 9.9, 10.1)];
+        for (c, e) in centers.into_iter().zip(expected) {
+            assert!((c - e).magnitude() < 1e-5, "{c} != {e}");
+        }
+
+        // the same code works for an arbitrary number of dimensions
+        let states = clusters.map(|v| SVec::new([v.x, v.y, v.z, -v.x, 1.0]));
+        let centers = k_means_step(&states, [SVec::zero(), SVec::fill(5.1)]);
+        for (c, e) in centers.into_iter().zip(expected) {
+            let e = SVec::new([e.x, e.y, e.z, -e.x, 1.0]);
+            assert!((c - e).magnitude() < 1e-5, "{c} != {e}");
+        }
+        assert_eq!(
+            bounds(&[SVec::new([1, 5]), SVec::new([3, -2])]),
+            (SVec::new([1, -2]), SVec::new([3, 5]))
+        );
+    }
+
+    #[test]
+    fn trait_items() {
+        assert_eq!(<Vec2 as Vector>::DIM, 2);
+        assert_eq!(<Vec4 as Vector>::DIM, 4);
+        let v = Vec4::from_array([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.to_array(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.components().sum::<f32>(), 10.0);
+        assert_eq!(v.map(|c| c * 2.0), v * 2.0);
+        assert_eq!(Vector::dot(v, v), 30.0);
+        assert_eq!(Vector::magnitude(Vec3::new(2.0, 3.0, 6.0)), 7.0);
+        assert_eq!(<Vec3i as Vector>::splat(3), Vec3i::fill(3));
+
+        assert_eq!(<SVec<f32, 6> as Vector>::DIM, 6);
+        let s = SVec::<f64, 5>::from_array([1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(Vector::dot(s, s), 55.0);
+        assert_eq!(s.components().last(), Some(5.0));
+    }
+
+    #[test]
+    fn no_ambiguity_with_gen_type() {
+        #[allow(unused_imports)]
+        use crate::shader::GenType;
+        let v = Vec3::splat(1.0);
+        assert_eq!(v.dot(Vec3::new(1.0, 2.0, 3.0)), 6.0);
+        assert_eq!(v.map(|c| c * 2.0), Vec3::fill(2.0));
+    }
+}